@@ -0,0 +1,208 @@
+//! Unsigned integers of any size, for answers that don't fit in a machine word
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign};
+
+/// Each limb holds nine decimal digits, which keeps printing in decimal simple
+const LIMB_BASE: u32 = 1_000_000_000;
+const LIMB_DIGITS: usize = 9;
+
+/// An unsigned integer that grows as needed instead of overflowing
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Little-endian limbs below `LIMB_BASE`, without trailing zero limbs. Zero has no limbs.
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        BigUint::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// The number written with `digits`, most significant first. Every digit must be below 10.
+    pub fn from_digits(digits: &[u8]) -> BigUint {
+        let mut limbs: Vec<u32> = digits
+            .rchunks(LIMB_DIGITS)
+            .map(|chunk| {
+                chunk.iter().fold(0, |acc, &digit| {
+                    debug_assert!(digit < 10, "{digit} is not a decimal digit");
+                    acc * 10 + u32::from(digit)
+                })
+            })
+            .collect();
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        BigUint { limbs }
+    }
+
+    /// Number of decimal digits. Zero has one digit.
+    pub fn digit_count(&self) -> usize {
+        match self.limbs.last() {
+            None => 1,
+            Some(&top) => (self.limbs.len() - 1) * LIMB_DIGITS + top.to_string().len(),
+        }
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> BigUint {
+        let mut limbs = Vec::new();
+        let mut value = value;
+        while value > 0 {
+            limbs.push((value % u64::from(LIMB_BASE)) as u32);
+            value /= u64::from(LIMB_BASE);
+        }
+        BigUint { limbs }
+    }
+}
+
+/// Fails if the value is larger than `u64::MAX`
+impl TryFrom<&BigUint> for u64 {
+    type Error = String;
+    fn try_from(value: &BigUint) -> Result<u64, Self::Error> {
+        value
+            .limbs
+            .iter()
+            .rev()
+            .try_fold(0_u64, |acc, &limb| {
+                acc.checked_mul(u64::from(LIMB_BASE))?
+                    .checked_add(u64::from(limb))
+            })
+            .ok_or_else(|| format!("{value} doesn't fit in a u64"))
+    }
+}
+
+impl PartialEq<u64> for BigUint {
+    fn eq(&self, other: &u64) -> bool {
+        u64::try_from(self) == Ok(*other)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl AddAssign<&BigUint> for BigUint {
+    fn add_assign(&mut self, rhs: &BigUint) {
+        if self.limbs.len() < rhs.limbs.len() {
+            self.limbs.resize(rhs.limbs.len(), 0);
+        }
+        let mut carry = 0;
+        for (idx, limb) in self.limbs.iter_mut().enumerate() {
+            let sum = *limb + rhs.limbs.get(idx).copied().unwrap_or(0) + carry;
+            *limb = sum % LIMB_BASE;
+            carry = sum / LIMB_BASE;
+        }
+        if carry > 0 {
+            self.limbs.push(carry);
+        }
+    }
+}
+
+impl Add<&BigUint> for BigUint {
+    type Output = BigUint;
+    fn add(mut self, rhs: &BigUint) -> BigUint {
+        self += rhs;
+        self
+    }
+}
+
+impl std::iter::Sum for BigUint {
+    fn sum<I: Iterator<Item = BigUint>>(iter: I) -> BigUint {
+        iter.fold(BigUint::zero(), |acc, value| acc + &value)
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut limbs = self.limbs.iter().rev();
+        let Some(top) = limbs.next() else {
+            return f.pad("0");
+        };
+        let mut s = top.to_string();
+        for limb in limbs {
+            s.push_str(&format!("{limb:0LIMB_DIGITS$}"));
+        }
+        f.pad(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digits() {
+        assert_eq!(BigUint::from_digits(&[]), BigUint::zero());
+        assert_eq!(BigUint::from_digits(&[0, 0, 0]), BigUint::zero());
+        assert_eq!(BigUint::from_digits(&[9, 8]), 98);
+        assert_eq!(BigUint::from_digits(&[0, 1, 2, 3]), 123);
+
+        let digits: Vec<u8> = (0..40).map(|i| (i % 9 + 1) as u8).collect();
+        let big = BigUint::from_digits(&digits);
+        let expected: String = digits.iter().map(|d| char::from(b'0' + d)).collect();
+        assert_eq!(big.to_string(), expected);
+        assert_eq!(big.digit_count(), 40);
+        assert_eq!(BigUint::zero().digit_count(), 1);
+        assert_eq!(BigUint::from(1_000_000_000).digit_count(), 10);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(BigUint::from(1_000_000_007).to_string(), "1000000007");
+        assert_eq!(BigUint::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(format!("{:>5}", BigUint::from(42)), "   42");
+    }
+
+    #[test]
+    fn addition() {
+        let mut sum = BigUint::from(u64::MAX);
+        sum += &BigUint::from(1);
+        assert_eq!(sum.to_string(), "18446744073709551616");
+        assert!(u64::try_from(&sum).is_err());
+
+        let nines = BigUint::from_digits(&[9; 30]);
+        let total = nines.clone() + &BigUint::from(1);
+        assert_eq!(total.to_string(), format!("1{}", "0".repeat(30)));
+
+        let values = [123_456_789_u64, 987_654_321, 5, u64::MAX / 2];
+        let total: BigUint = values.iter().map(|&v| BigUint::from(v)).sum();
+        let expected: u128 = values.iter().map(|&v| u128::from(v)).sum();
+        assert_eq!(total.to_string(), expected.to_string());
+        assert_eq!(u64::try_from(&BigUint::from(u64::MAX)), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn ordering() {
+        let values = [0, 1, 999_999_999, 1_000_000_000, 1_000_000_001, u64::MAX];
+        for a in values {
+            for b in values {
+                assert_eq!(
+                    BigUint::from(a).cmp(&BigUint::from(b)),
+                    a.cmp(&b),
+                    "{a} {b}"
+                );
+            }
+        }
+        let max = BigUint::from(u64::MAX);
+        assert!(BigUint::from_digits(&[1; 25]) > max);
+    }
+}
@@ -0,0 +1,295 @@
+//! Regression checking of computed answers against the known answers file
+
+use crate::input;
+use crate::runner::{DayReport, Failure};
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Name of the known answers file
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Find the known answers. They are searched for in the same places as the input, except that the
+/// fallback is the directory above this crate rather than its `input` directory.
+pub fn find_answers() -> Result<PathBuf, String> {
+    let paths = input::search_paths(ANSWERS_FILE, &input::checkout_dir());
+    match paths.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => {
+            let searched: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+            Err(format!(
+                "Known answers were not found. Looked for: {}",
+                searched.join(", ")
+            ))
+        }
+    }
+}
+
+/// Answers that have already earned a star, keyed by problem and part.
+///
+/// The file is a small subset of TOML - one `[day.N]` table per problem, containing `part_1` and
+/// `part_2` keys with integer or string values:
+///
+/// ```toml
+/// [day.1]
+/// part_1 = 1234
+/// part_2 = "5678"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, u8), String>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let text = read_to_string(path)
+            .map_err(|e| format!("Unable to read answers from {}: {e}", path.display()))?;
+        text.parse()
+            .map_err(|e| format!("Invalid answers file {}: {e}", path.display()))
+    }
+
+    pub fn get(&self, problem: u8, part: u8) -> Option<&str> {
+        self.0.get(&(problem, part)).map(|answer| answer.as_str())
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Answers, Self::Err> {
+        let mut answers = BTreeMap::new();
+        let mut problem = None;
+
+        for (idx, line) in s.lines().enumerate() {
+            let line_number = idx + 1;
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let day = header
+                    .strip_suffix(']')
+                    .and_then(|header| header.trim().strip_prefix("day."))
+                    .and_then(|day| day.parse::<u8>().ok())
+                    .ok_or_else(|| {
+                        format!("Line {line_number}: expected a [day.N] table, found \"{line}\"")
+                    })?;
+                problem = Some(day);
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!(
+                    "Line {line_number}: expected `part_N = answer`, found \"{line}\""
+                ));
+            };
+            let Some(problem) = problem else {
+                return Err(format!(
+                    "Line {line_number}: answer given before any [day.N] table"
+                ));
+            };
+            let part = match key.trim() {
+                "part_1" => 1,
+                "part_2" => 2,
+                key => {
+                    return Err(format!(
+                        "Line {line_number}: unknown key \"{key}\". Expected part_1 or part_2"
+                    ));
+                }
+            };
+            let value = parse_value(value.trim()).ok_or_else(|| {
+                format!("Line {line_number}: invalid answer \"{}\"", value.trim())
+            })?;
+
+            if answers.insert((problem, part), value).is_some() {
+                return Err(format!(
+                    "Line {line_number}: duplicate answer for problem {problem} part {part}"
+                ));
+            }
+        }
+
+        Ok(Answers(answers))
+    }
+}
+
+/// Remove a trailing `#` comment, ignoring any `#` inside a quoted string
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (idx, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..idx],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// An integer, or a string without escape sequences
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        let inner = quoted.strip_suffix('"')?;
+        return (!inner.contains(['"', '\\'])).then(|| inner.to_string());
+    }
+    let digits = value.strip_prefix('-').unwrap_or(value).replace('_', "");
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(value.replace('_', ""))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Verdict {
+    Pass,
+    /// The answer differs from the known one, or the part failed to produce an answer
+    Fail {
+        expected: Option<String>,
+    },
+    /// There is no known answer, or no input to compute one from
+    Unknown,
+}
+
+/// What running a part produced, borrowed from a [`DayReport`]
+enum Computed<'a> {
+    Answer(&'a str),
+    Error(&'a str),
+    MissingInput(&'a str),
+}
+
+/// Compare every part in `reports` with `answers`, printing a line per part and a summary.
+/// Returns `false` if any part failed.
+pub fn print_check(reports: &[DayReport], answers: &Answers) -> bool {
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    for report in reports {
+        for (part, computed) in computed_answers(report) {
+            let verdict = verdict(answers.get(report.day, part), &computed);
+            let detail = match (&verdict, &computed) {
+                (Verdict::Fail { expected }, Computed::Answer(answer)) => format!(
+                    "expected {}, got {answer}",
+                    expected.as_deref().unwrap_or("?")
+                ),
+                (_, Computed::Answer(answer)) => answer.to_string(),
+                (_, Computed::Error(e)) => e.to_string(),
+                (_, Computed::MissingInput(e)) => format!("input missing: {e}"),
+            };
+            let label = match verdict {
+                Verdict::Pass => {
+                    passed += 1;
+                    "PASS"
+                }
+                Verdict::Fail { .. } => {
+                    failed += 1;
+                    "FAIL"
+                }
+                Verdict::Unknown => {
+                    unknown += 1;
+                    "UNKNOWN"
+                }
+            };
+            println!("Problem {:02} Part {part}: {label} ({detail})", report.day);
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {unknown} unknown");
+    failed == 0
+}
+
+/// What each selected part of a day produced
+fn computed_answers(report: &DayReport) -> Vec<(u8, Computed<'_>)> {
+    match &report.parts {
+        Ok(parts) => parts
+            .iter()
+            .map(|part| {
+                let computed = match &part.answer {
+                    Ok(answer) => Computed::Answer(answer),
+                    Err(e) => Computed::Error(e),
+                };
+                (part.part, computed)
+            })
+            .collect(),
+        Err(failure) => report
+            .selected
+            .iter()
+            .map(|&part| {
+                let computed = match failure {
+                    Failure::MissingInput(e) => Computed::MissingInput(e),
+                    Failure::Error(e) => Computed::Error(e),
+                };
+                (part, computed)
+            })
+            .collect(),
+    }
+}
+
+fn verdict(expected: Option<&str>, computed: &Computed) -> Verdict {
+    match (expected, computed) {
+        (_, Computed::MissingInput(_)) | (None, Computed::Answer(_)) => Verdict::Unknown,
+        (Some(expected), Computed::Answer(answer)) if expected == *answer => Verdict::Pass,
+        (expected, _) => Verdict::Fail {
+            expected: expected.map(str::to_string),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn parse_answers() {
+        let answers: Answers = indoc! {r#"
+            # Known answers
+            [day.1]
+            part_1 = 1234
+            part_2 = "5678" # earned on the 1st
+
+            [ day.12 ]
+            part_1 = 1_000_000
+        "#}
+        .parse()
+        .unwrap();
+
+        assert_eq!(answers.get(1, 1), Some("1234"));
+        assert_eq!(answers.get(1, 2), Some("5678"));
+        assert_eq!(answers.get(12, 1), Some("1000000"));
+        assert_eq!(answers.get(12, 2), None);
+        assert_eq!(answers.get(2, 1), None);
+        assert_eq!("".parse(), Ok(Answers::default()));
+    }
+
+    #[test]
+    fn invalid_answers() {
+        assert!("part_1 = 1".parse::<Answers>().is_err());
+        assert!("[day.x]\npart_1 = 1".parse::<Answers>().is_err());
+        assert!("[day.1]\npart_3 = 1".parse::<Answers>().is_err());
+        assert!("[day.1]\npart_1 = one".parse::<Answers>().is_err());
+        assert!("[day.1]\npart_1 = \"1".parse::<Answers>().is_err());
+        assert!(
+            "[day.1]\npart_1 = 1\npart_1 = 2"
+                .parse::<Answers>()
+                .is_err()
+        );
+        assert!("[day.1]\npart_1".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(verdict(Some("43"), &Computed::Answer("43")), Verdict::Pass);
+        assert_eq!(
+            verdict(Some("43"), &Computed::Answer("42")),
+            Verdict::Fail {
+                expected: Some(String::from("43"))
+            }
+        );
+        assert_eq!(verdict(None, &Computed::Answer("42")), Verdict::Unknown);
+        assert_eq!(
+            verdict(None, &Computed::Error("Bad grid")),
+            Verdict::Fail { expected: None }
+        );
+        assert_eq!(
+            verdict(Some("43"), &Computed::MissingInput("4.1.txt")),
+            Verdict::Unknown
+        );
+    }
+}
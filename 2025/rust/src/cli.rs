@@ -0,0 +1,347 @@
+//! Command-line argument parsing for the puzzle runner
+
+use aoc::InputSource;
+use aoc::report::Format;
+
+pub const USAGE: &str = "\
+Usage: rust [OPTIONS] [SELECTION]...
+
+Runs the selected puzzle solutions. All solved problems are run if no selection is given.
+
+Selections:
+  4           Both parts of problem 4
+  4.2         Only part 2 of problem 4
+  1-3         Both parts of problems 1 through 3
+
+Options:
+  --all       Run every solved problem
+  --time      Print how long reading, parsing and each part took
+  --bench N   Repeat every phase N times and print min/median/mean durations
+  --input F   Read input for the (single) selected problem from file F, or stdin if F is -.
+              Otherwise input is searched for as <problem>.1.txt in $AOC_INPUT_DIR, then the
+              current directory, then the input directory next to this crate
+  --explain   Print how each answer is made up, for problems that support it (2 and 3)
+  --check     Compare answers with answers.toml, printing PASS/FAIL/UNKNOWN for each part.
+              Exits with a nonzero status if any part fails. answers.toml is searched for in
+              $AOC_INPUT_DIR, then the current directory, then the directory above this crate
+  --format F  Output format: text (default), json or csv. JSON and CSV contain one record per
+              problem and part with its answer, status, error and durations in nanoseconds
+  -h, --help  Print this message";
+
+/// A problem (and optionally a single part of it) requested on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selection {
+    pub problem: u8,
+    /// `None` selects both parts
+    pub part: Option<u8>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    pub selections: Vec<Selection>,
+    /// Print per-phase durations alongside the answers
+    pub time: bool,
+    /// Number of times to repeat each phase when benchmarking
+    pub bench: Option<usize>,
+    pub format: Format,
+    /// Compare answers against the known answers file
+    pub check: bool,
+    /// Print an explanation of each answer
+    pub explain: bool,
+    pub input: InputSource,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(Options),
+    Help,
+}
+
+/// Parse the arguments following the program name. `solved` lists the problems that have
+/// solutions, used to expand `--all` and reject problems that don't exist yet.
+pub fn parse_args<I, S>(args: I, solved: &[u8]) -> Result<Command, String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut options = Options::default();
+    let mut selections = Vec::new();
+    let mut all = false;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let arg = arg.as_ref();
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if arg.starts_with("--") => (flag, Some(value.to_string())),
+            _ => (arg, None),
+        };
+        let is_switch = matches!(
+            flag,
+            "--help" | "--all" | "--time" | "--check" | "--explain"
+        );
+        if is_switch && inline_value.is_some() {
+            return Err(format!("Option \"{flag}\" doesn't take a value."));
+        }
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next().map(|v| v.as_ref().to_string()))
+                .ok_or_else(|| format!("Option \"{flag}\" requires a value."))
+        };
+
+        match flag {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--all" => all = true,
+            "--time" => options.time = true,
+            "--check" => options.check = true,
+            "--explain" => options.explain = true,
+            "--input" => options.input = InputSource::from_arg(&value()?),
+            "--format" => options.format = value()?.parse()?,
+            "--bench" => {
+                let value = value()?;
+                match value.parse::<usize>() {
+                    Ok(repeats) if repeats > 0 => options.bench = Some(repeats),
+                    _ => {
+                        return Err(format!(
+                            "Invalid repeat count \"{value}\". Expected a positive integer."
+                        ));
+                    }
+                }
+            }
+            _ if arg.starts_with('-') => {
+                return Err(format!("Unrecognized option \"{arg}\"."));
+            }
+            _ => selections.extend(parse_selection(arg)?),
+        }
+    }
+
+    if all || selections.is_empty() {
+        selections = solved
+            .iter()
+            .map(|&problem| Selection {
+                problem,
+                part: None,
+            })
+            .collect();
+    }
+
+    for selection in &selections {
+        if !solved.contains(&selection.problem) {
+            return Err(format!(
+                "Problem {:02} has not been solved yet.",
+                selection.problem
+            ));
+        }
+    }
+
+    if options.check && (options.bench.is_some() || options.format != Format::Text) {
+        return Err(String::from(
+            "--check can't be combined with --bench or --format.",
+        ));
+    }
+
+    if options.explain
+        && (options.check || options.bench.is_some() || options.format != Format::Text)
+    {
+        return Err(String::from(
+            "--explain can't be combined with --check, --bench or --format.",
+        ));
+    }
+
+    let mut problems: Vec<u8> = selections.iter().map(|s| s.problem).collect();
+    problems.dedup();
+    if options.input != InputSource::Search && problems.len() != 1 {
+        return Err(String::from(
+            "--input requires exactly one problem to be selected.",
+        ));
+    }
+
+    options.selections = selections;
+    Ok(Command::Run(options))
+}
+
+/// Parse a single `N`, `N.P` or `A-B` selection
+fn parse_selection(arg: &str) -> Result<Vec<Selection>, String> {
+    let parse_problem = |s: &str| {
+        s.parse::<u8>()
+            .map_err(|_| format!("Invalid problem number \"{s}\" in \"{arg}\"."))
+    };
+
+    if let Some((first, last)) = arg.split_once('-') {
+        let (first, last) = (parse_problem(first)?, parse_problem(last)?);
+        if first > last {
+            return Err(format!("Range \"{arg}\" is reversed."));
+        }
+        return Ok((first..=last)
+            .map(|problem| Selection {
+                problem,
+                part: None,
+            })
+            .collect());
+    }
+
+    if let Some((problem, part)) = arg.split_once('.') {
+        let part = match part {
+            "1" => 1,
+            "2" => 2,
+            _ => {
+                return Err(format!(
+                    "Invalid part \"{part}\" in \"{arg}\". Expected 1 or 2."
+                ));
+            }
+        };
+        return Ok(vec![Selection {
+            problem: parse_problem(problem)?,
+            part: Some(part),
+        }]);
+    }
+
+    Ok(vec![Selection {
+        problem: parse_problem(arg)?,
+        part: None,
+    }])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn both(problem: u8) -> Selection {
+        Selection {
+            problem,
+            part: None,
+        }
+    }
+
+    fn run(args: &[&str]) -> Result<Command, String> {
+        parse_args(args, &[1, 2, 3, 4, 5, 6])
+    }
+
+    fn selected(selections: Vec<Selection>) -> Result<Command, String> {
+        Ok(Command::Run(Options {
+            selections,
+            ..Options::default()
+        }))
+    }
+
+    #[test]
+    fn no_arguments_runs_everything() {
+        let expected = (1..=6).map(both).collect();
+        assert_eq!(run(&[]), selected(expected));
+        assert_eq!(run(&["--all"]), run(&[]));
+    }
+
+    #[test]
+    fn selections() {
+        assert_eq!(run(&["4"]), selected(vec![both(4)]));
+        assert_eq!(
+            run(&["4.2"]),
+            selected(vec![Selection {
+                problem: 4,
+                part: Some(2)
+            }])
+        );
+        assert_eq!(
+            run(&["1-3", "6"]),
+            selected(vec![both(1), both(2), both(3), both(6)])
+        );
+        assert_eq!(run(&["--help", "4"]), Ok(Command::Help));
+    }
+
+    #[test]
+    fn timing_options() {
+        let Ok(Command::Run(options)) = run(&["--time", "2"]) else {
+            panic!("Expected options to parse");
+        };
+        assert!(options.time);
+        assert_eq!(options.bench, None);
+        assert_eq!(options.selections, vec![both(2)]);
+
+        let Ok(Command::Run(options)) = run(&["--bench", "10", "3"]) else {
+            panic!("Expected options to parse");
+        };
+        assert_eq!(options.bench, Some(10));
+        assert_eq!(options.selections, vec![both(3)]);
+
+        let Ok(Command::Run(options)) = run(&["--bench=5"]) else {
+            panic!("Expected options to parse");
+        };
+        assert_eq!(options.bench, Some(5));
+
+        assert!(run(&["--bench"]).is_err());
+        assert!(run(&["--bench", "0"]).is_err());
+        assert!(run(&["--bench", "fast"]).is_err());
+    }
+
+    #[test]
+    fn flags_without_values() {
+        for flag in ["--all", "--time", "--check", "--explain", "--help"] {
+            assert_eq!(
+                run(&[&format!("{flag}=false"), "2"]),
+                Err(format!("Option \"{flag}\" doesn't take a value."))
+            );
+        }
+        assert!(run(&["--time=", "2"]).is_err());
+        assert_eq!(
+            run(&["--colour=never"]),
+            Err(String::from("Unrecognized option \"--colour=never\"."))
+        );
+    }
+
+    #[test]
+    fn format_option() {
+        let Ok(Command::Run(options)) = run(&["--format", "json"]) else {
+            panic!("Expected options to parse");
+        };
+        assert_eq!(options.format, Format::Json);
+
+        let Ok(Command::Run(options)) = run(&["--format=csv", "1.1"]) else {
+            panic!("Expected options to parse");
+        };
+        assert_eq!(options.format, Format::Csv);
+
+        assert!(run(&["--format", "yaml"]).is_err());
+        assert!(run(&["--check", "--format", "json"]).is_err());
+        assert!(run(&["--check", "--bench", "3"]).is_err());
+        assert!(run(&["--format"]).is_err());
+    }
+
+    #[test]
+    fn explain_option() {
+        let Ok(Command::Run(options)) = run(&["--explain", "2.1"]) else {
+            panic!("Expected options to parse");
+        };
+        assert!(options.explain);
+
+        assert!(run(&["--explain", "--check"]).is_err());
+        assert!(run(&["--explain", "--bench", "3"]).is_err());
+        assert!(run(&["--explain", "--format", "csv"]).is_err());
+    }
+
+    #[test]
+    fn input_option() {
+        let Ok(Command::Run(options)) = run(&["--input", "day4.txt", "4"]) else {
+            panic!("Expected options to parse");
+        };
+        assert_eq!(options.input, InputSource::File("day4.txt".into()));
+
+        let Ok(Command::Run(options)) = run(&["4.1", "4.2", "--input=-"]) else {
+            panic!("Expected options to parse");
+        };
+        assert_eq!(options.input, InputSource::Stdin);
+
+        assert!(run(&["--input", "-"]).is_err());
+        assert!(run(&["--input", "-", "1-2"]).is_err());
+    }
+
+    #[test]
+    fn invalid_selections() {
+        assert!(run(&["7"]).is_err());
+        assert!(run(&["0"]).is_err());
+        assert!(run(&["5-9"]).is_err());
+        assert!(run(&["3-1"]).is_err());
+        assert!(run(&["4.3"]).is_err());
+        assert!(run(&["four"]).is_err());
+        assert!(run(&["--verbose"]).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+//! Error type shared by every problem
+
+use crate::InputError;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum AocError {
+    /// Puzzle input doesn't have the expected format. `line` and `column` are 1-based, and `text`
+    /// is the offending part of the input.
+    Parse {
+        line: Option<usize>,
+        column: Option<usize>,
+        text: String,
+        message: String,
+    },
+    /// Input was understood, but describes a puzzle that can't be solved
+    InvalidState(String),
+    /// Input couldn't be read
+    Input(InputError),
+}
+
+impl AocError {
+    /// A parse error with no position information yet - see [`AocError::at_line`] and
+    /// [`AocError::at_column`]
+    pub fn parse(message: impl Into<String>, text: impl Into<String>) -> AocError {
+        AocError::Parse {
+            line: None,
+            column: None,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid_state(message: impl Into<String>) -> AocError {
+        AocError::InvalidState(message.into())
+    }
+
+    /// Set the line of a parse error if it doesn't already have one. Other errors are unchanged.
+    pub fn at_line(mut self, line_number: usize) -> AocError {
+        if let AocError::Parse { line, .. } = &mut self {
+            line.get_or_insert(line_number);
+        }
+        self
+    }
+
+    /// Set the column of a parse error if it doesn't already have one. Other errors are
+    /// unchanged.
+    pub fn at_column(mut self, column_number: usize) -> AocError {
+        if let AocError::Parse { column, .. } = &mut self {
+            column.get_or_insert(column_number);
+        }
+        self
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Parse {
+                line,
+                column,
+                text,
+                message,
+            } => {
+                match (line, column) {
+                    (Some(line), Some(column)) => write!(f, "Line {line}, column {column}: ")?,
+                    (Some(line), None) => write!(f, "Line {line}: ")?,
+                    (None, Some(column)) => write!(f, "Column {column}: ")?,
+                    (None, None) => {}
+                }
+                write!(f, "{message}")?;
+                if !text.is_empty() {
+                    write!(f, ": \"{text}\"")?;
+                }
+                Ok(())
+            }
+            AocError::InvalidState(message) => write!(f, "{message}"),
+            AocError::Input(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Input(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<InputError> for AocError {
+    fn from(e: InputError) -> AocError {
+        AocError::Input(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let error = AocError::parse("Expected a digit", "12a");
+        assert_eq!(error.to_string(), "Expected a digit: \"12a\"");
+
+        let error = error.at_line(3).at_column(7);
+        assert_eq!(
+            error.to_string(),
+            "Line 3, column 7: Expected a digit: \"12a\""
+        );
+
+        // Positions set closer to the source of the error take precedence
+        let error = error.at_line(10).at_column(1);
+        assert!(matches!(
+            error,
+            AocError::Parse {
+                line: Some(3),
+                column: Some(7),
+                ..
+            }
+        ));
+
+        let error = AocError::invalid_state("Grid must have at least 2 rows.").at_line(1);
+        assert_eq!(error.to_string(), "Grid must have at least 2 rows.");
+    }
+}
@@ -0,0 +1,375 @@
+//! Rectangular 2D grid shared by problems whose input is a map of characters
+
+use crate::AocError;
+use std::str::FromStr;
+
+/// Offsets of the four orthogonal neighbours of a cell
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of all eight neighbours of a cell, including diagonals
+pub const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A type that can be stored in a grid parsed from text, one character per cell
+pub trait Cell: Sized {
+    /// Returns `None` if `c` doesn't represent a cell of this type
+    fn from_char(c: char) -> Option<Self>;
+}
+
+impl Cell for char {
+    fn from_char(c: char) -> Option<char> {
+        Some(c)
+    }
+}
+
+/// Cells stored row by row. `x` is the column and `y` is the row, both counted from the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` x `height` grid with every cell set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            data: vec![value; width * height],
+        }
+    }
+
+    /// Parse one row per non-empty line of `s`, converting each character with `parse_cell`.
+    /// Every row must have the same number of characters.
+    pub fn parse_with<F>(s: &str, mut parse_cell: F) -> Result<Grid<T>, AocError>
+    where
+        F: FnMut(char) -> Result<T, AocError>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut data = Vec::new();
+
+        for (idx, line) in s.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let row_start = data.len();
+            for (column, c) in line.chars().enumerate() {
+                let cell = parse_cell(c).map_err(|e| e.at_line(idx + 1).at_column(column + 1))?;
+                data.push(cell);
+            }
+
+            let row_width = data.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(AocError::parse(
+                        format!("Non-uniform row length. Expected {width}, got {row_width}"),
+                        line,
+                    )
+                    .at_line(idx + 1));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        let Some(width) = width else {
+            return Err(AocError::parse("Empty grid", ""));
+        };
+
+        Ok(Grid {
+            width,
+            height,
+            data,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_in_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Returns `None` if (`x`,`y`) is out of bounds
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.is_in_bounds(x, y) {
+            return Some(&self.data[y * self.width + x]);
+        }
+        None
+    }
+
+    /// Returns `None` if (`x`,`y`) is out of bounds
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.is_in_bounds(x, y) {
+            return Some(&mut self.data[y * self.width + x]);
+        }
+        None
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> Result<(), AocError> {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                Ok(())
+            }
+            None => Err(AocError::invalid_state(format!(
+                "Position ({x},{y}) out of bounds for grid of size ({width},{height})."
+            ))),
+        }
+    }
+
+    /// Cells of row `y` from left to right
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            return Some(&self.data[y * self.width..(y + 1) * self.width]);
+        }
+        None
+    }
+
+    /// Iterator over the rows of the grid from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.data[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Cells of column `x` from top to bottom. Empty if `x` is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let rows = if x < self.width { self.height } else { 0 };
+        (0..rows).map(move |y| &self.data[y * self.width + x])
+    }
+
+    /// Iterator over the columns of the grid from left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every position in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Position offset from (`x`,`y`) by (`dx`,`dy`), or `None` if that is out of bounds
+    pub fn offset(&self, x: usize, y: usize, (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let nx = x.checked_add_signed(dx)?;
+        let ny = y.checked_add_signed(dy)?;
+        self.is_in_bounds(nx, ny).then_some((nx, ny))
+    }
+
+    /// Position offset from (`x`,`y`) by (`dx`,`dy`), wrapping around the edges of the grid as if
+    /// it were a torus. Returns `None` if the grid has no cells to wrap around.
+    pub fn wrapping_offset(
+        &self,
+        x: usize,
+        y: usize,
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        let wrap = |pos: usize, delta: isize, size: usize| {
+            let delta = delta.rem_euclid(size as isize) as usize;
+            (pos % size + delta) % size
+        };
+        Some((wrap(x, dx, self.width), wrap(y, dy, self.height)))
+    }
+
+    /// In-bounds positions offset from (`x`,`y`) by each of `offsets`
+    pub fn neighbours<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.offset(x, y, offset))
+    }
+
+    /// In-bounds orthogonal neighbours of (`x`,`y`)
+    pub fn neighbours_4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &ORTHOGONAL)
+    }
+
+    /// In-bounds neighbours of (`x`,`y`), including diagonals
+    pub fn neighbours_8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &ALL_AROUND)
+    }
+
+    /// A grid of the same size with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Cell> FromStr for Grid<T> {
+    type Err = AocError;
+    fn from_str(s: &str) -> Result<Grid<T>, Self::Err> {
+        Grid::parse_with(s, |c| {
+            T::from_char(c).ok_or_else(|| AocError::parse("Unexpected character in grid", c))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        abcd
+        efgh
+        ijkl
+    "};
+
+    #[derive(Debug, PartialEq)]
+    enum Tile {
+        Wall,
+        Floor,
+    }
+
+    impl Cell for Tile {
+        fn from_char(c: char) -> Option<Tile> {
+            match c {
+                '#' => Some(Tile::Wall),
+                '.' => Some(Tile::Floor),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(grid.width(), 4);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.get(0, 0), Some(&'a'));
+        assert_eq!(grid.get(3, 2), Some(&'l'));
+        assert_eq!(grid.get(4, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+
+        let tiles: Grid<Tile> = "#.\n.#\n".parse().unwrap();
+        assert_eq!(tiles.get(1, 0), Some(&Tile::Floor));
+        assert_eq!(tiles.get(1, 1), Some(&Tile::Wall));
+
+        let digits = Grid::parse_with("12\n34\n", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| AocError::parse("Not a digit", c))
+        })
+        .unwrap();
+        assert_eq!(digits.get(1, 1), Some(&4));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = "#.\n.x\n".parse::<Grid<Tile>>().err().unwrap();
+        assert!(matches!(
+            error,
+            AocError::Parse {
+                line: Some(2),
+                column: Some(2),
+                ..
+            }
+        ));
+
+        let error = "abc\nde\n".parse::<Grid<char>>().err().unwrap();
+        assert!(matches!(error, AocError::Parse { line: Some(2), .. }));
+
+        assert!("".parse::<Grid<char>>().is_err());
+        assert!("\n\n".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn set_and_get_mut() {
+        let mut grid = Grid::new(3, 2, 0);
+        grid.set(2, 1, 5).unwrap();
+        *grid.get_mut(0, 1).unwrap() += 1;
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[0, 0, 0], [1, 0, 5]]);
+
+        assert!(grid.set(3, 0, 1).is_err());
+        assert!(grid.set(0, 2, 1).is_err());
+        assert!(grid.get_mut(3, 1).is_none());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(grid.row(1), Some(&['e', 'f', 'g', 'h'][..]));
+        assert_eq!(grid.row(3), None);
+
+        let columns: Vec<String> = grid.columns().map(|col| col.collect()).collect();
+        assert_eq!(columns, ["aei", "bfj", "cgk", "dhl"]);
+        assert_eq!(grid.column(4).count(), 0);
+
+        let positions: Vec<_> = grid.positions().collect();
+        assert_eq!(positions.len(), 12);
+        assert_eq!(positions[0], (0, 0));
+        assert_eq!(positions[5], (1, 1));
+        assert_eq!(positions[11], (3, 2));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        let mut corner: Vec<_> = grid.neighbours_8(0, 0).collect();
+        corner.sort();
+        assert_eq!(corner, [(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(grid.neighbours_4(0, 0).count(), 2);
+
+        assert_eq!(grid.neighbours_8(1, 1).count(), 8);
+        assert_eq!(grid.neighbours_4(1, 1).count(), 4);
+
+        let mut edge: Vec<_> = grid.neighbours_4(3, 1).collect();
+        edge.sort();
+        assert_eq!(edge, [(2, 1), (3, 0), (3, 2)]);
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(grid.offset(1, 1, (-1, 1)), Some((0, 2)));
+        assert_eq!(grid.offset(0, 1, (-1, 0)), None);
+        assert_eq!(grid.offset(1, 2, (0, 1)), None);
+
+        assert_eq!(grid.wrapping_offset(0, 0, (-1, -1)), Some((3, 2)));
+        assert_eq!(grid.wrapping_offset(3, 2, (1, 1)), Some((0, 0)));
+        assert_eq!(grid.wrapping_offset(1, 1, (9, -7)), Some((2, 0)));
+
+        assert_eq!(Grid::new(0, 0, '.').wrapping_offset(0, 0, (1, 1)), None);
+        assert_eq!(Grid::new(3, 0, '.').wrapping_offset(1, 0, (1, 0)), None);
+    }
+
+    #[test]
+    fn map() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        let upper = grid.map(|c| c.to_ascii_uppercase());
+        assert_eq!(upper.get(2, 1), Some(&'G'));
+        assert_eq!(upper.width(), grid.width());
+        assert_eq!(upper.height(), grid.height());
+    }
+}
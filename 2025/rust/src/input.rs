@@ -0,0 +1,259 @@
+//! Locating and reading puzzle input
+
+use std::env;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a directory that contains `<problem>.<part>.txt` input files
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Why puzzle input couldn't be read
+#[derive(Debug)]
+pub enum InputError {
+    /// None of the searched paths exist
+    NotFound {
+        searched: Vec<PathBuf>,
+    },
+    PermissionDenied {
+        path: PathBuf,
+    },
+    /// The input isn't valid UTF-8. `path` is `None` for stdin.
+    InvalidUtf8 {
+        path: Option<PathBuf>,
+    },
+    /// Any other I/O failure. `path` is `None` for stdin.
+    Io {
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+}
+
+impl InputError {
+    fn from_io(path: Option<&Path>, e: io::Error) -> InputError {
+        let path = path.map(Path::to_path_buf);
+        match (e.kind(), path) {
+            (io::ErrorKind::NotFound, Some(path)) => InputError::NotFound {
+                searched: vec![path],
+            },
+            (io::ErrorKind::PermissionDenied, Some(path)) => InputError::PermissionDenied { path },
+            (io::ErrorKind::InvalidData, path) => InputError::InvalidUtf8 { path },
+            (_, path) => InputError::Io { path, source: e },
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let location = |path: &Option<PathBuf>| match path {
+            Some(path) => path.display().to_string(),
+            None => String::from("stdin"),
+        };
+
+        match self {
+            InputError::NotFound { searched } => {
+                let searched: Vec<String> =
+                    searched.iter().map(|p| p.display().to_string()).collect();
+                write!(
+                    f,
+                    "Puzzle input was not found. Download your puzzle input to one of: {}",
+                    searched.join(", ")
+                )
+            }
+            InputError::PermissionDenied { path } => {
+                write!(
+                    f,
+                    "Permission denied reading puzzle input at {}",
+                    path.display()
+                )
+            }
+            InputError::InvalidUtf8 { path } => {
+                write!(f, "Puzzle input in {} is not valid UTF-8", location(path))
+            }
+            InputError::Io { path, source } => {
+                write!(
+                    f,
+                    "Unable to read puzzle input from {}: {source}",
+                    location(path)
+                )
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Where to read a day's input from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// Search the usual locations - see [`input_to_string`]
+    #[default]
+    Search,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// `-` means stdin, anything else is a file path
+    pub fn from_arg(arg: &str) -> InputSource {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Read input for `problem` from this source
+    pub fn read(&self, problem: u8, part: u8) -> Result<String, InputError> {
+        match self {
+            InputSource::Search => input_to_string(problem, part),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| InputError::from_io(None, e))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// Directory above this crate in a checkout of this repository, which holds the `input`
+/// directory and the known answers
+pub fn checkout_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("CARGO_MANIFEST_DIR has a parent directory")
+        .to_path_buf()
+}
+
+/// Directory the input lives in when running from a checkout of this repository
+fn default_input_dir() -> PathBuf {
+    checkout_dir().join("input")
+}
+
+/// Paths that are tried, in order, when searching for `file_name`
+fn candidates(
+    file_name: &str,
+    env_dir: Option<OsString>,
+    cwd: Option<PathBuf>,
+    default_dir: &Path,
+) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    if let Some(dir) = env_dir.filter(|dir| !dir.is_empty()) {
+        paths.push(PathBuf::from(dir).join(file_name));
+    }
+    if let Some(cwd) = cwd {
+        paths.push(cwd.join(file_name));
+    }
+    paths.push(default_dir.join(file_name));
+    paths
+}
+
+/// Where to look for `file_name`, in order: the directory named by [`INPUT_DIR_VAR`], the current
+/// directory, then `default_dir`
+pub fn search_paths(file_name: &str, default_dir: &Path) -> Vec<PathBuf> {
+    candidates(
+        file_name,
+        env::var_os(INPUT_DIR_VAR),
+        env::current_dir().ok(),
+        default_dir,
+    )
+}
+
+/// Read the puzzle input for `problem`, looking for `<problem>.<part>.txt` in the directory named
+/// by [`INPUT_DIR_VAR`], then the current directory, then the `input` directory next to this
+/// crate.
+pub fn input_to_string(problem: u8, part: u8) -> Result<String, InputError> {
+    let file_name = format!("{problem}.{part}.txt");
+    let paths = search_paths(&file_name, &default_input_dir());
+
+    match paths.iter().find(|path| path.is_file()) {
+        Some(path) => read_file(path),
+        None => Err(InputError::NotFound { searched: paths }),
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    read_to_string(path).map_err(|e| InputError::from_io(Some(path), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_order() {
+        let paths = candidates(
+            "4.1.txt",
+            Some(OsString::from("/inputs")),
+            Some(PathBuf::from("/work")),
+            &default_input_dir(),
+        );
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("/inputs/4.1.txt"),
+                PathBuf::from("/work/4.1.txt"),
+                default_input_dir().join("4.1.txt"),
+            ]
+        );
+
+        let paths = candidates("4.1.txt", Some(OsString::new()), None, &default_input_dir());
+        assert_eq!(paths, vec![default_input_dir().join("4.1.txt")]);
+    }
+
+    #[test]
+    fn source_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("my/input.txt"),
+            InputSource::File(PathBuf::from("my/input.txt"))
+        );
+    }
+
+    #[test]
+    fn missing_file() {
+        let path = PathBuf::from("/this/does/not/exist.txt");
+        let error = InputSource::File(path.clone()).read(4, 1).unwrap_err();
+        assert!(matches!(&error, InputError::NotFound { searched } if *searched == [path]));
+        assert!(error.to_string().contains("/this/does/not/exist.txt"));
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let path = env::temp_dir().join(format!("aoc-invalid-utf8-{}.txt", std::process::id()));
+        std::fs::write(&path, [b'1', 0xff, b'\n']).unwrap();
+        let error = InputSource::File(path.clone()).read(4, 1).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(error, InputError::InvalidUtf8 { path: Some(p) } if p == path));
+    }
+
+    #[test]
+    fn io_error_kinds() {
+        let path = Path::new("4.1.txt");
+        let error = |kind| InputError::from_io(Some(path), io::Error::from(kind));
+        assert!(matches!(
+            error(io::ErrorKind::PermissionDenied),
+            InputError::PermissionDenied { .. }
+        ));
+        assert!(matches!(
+            error(io::ErrorKind::Interrupted),
+            InputError::Io { .. }
+        ));
+        assert!(matches!(
+            InputError::from_io(None, io::Error::from(io::ErrorKind::InvalidData)),
+            InputError::InvalidUtf8 { path: None }
+        ));
+    }
+}
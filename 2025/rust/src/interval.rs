@@ -0,0 +1,314 @@
+//! Sets of integers stored as ranges, shared by problems whose input is a list of ID ranges
+
+use std::ops::RangeInclusive;
+
+/// A set of `u64`s stored as inclusive spans. The spans are kept sorted, and no two of them
+/// overlap or touch, so every set has exactly one representation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    spans: Vec<RangeInclusive<u64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    /// The union of `ranges`, which can overlap and be in any order. Empty ranges are ignored.
+    pub fn merge<I>(ranges: I) -> IntervalSet
+    where
+        I: IntoIterator<Item = RangeInclusive<u64>>,
+    {
+        let mut ranges: Vec<RangeInclusive<u64>> =
+            ranges.into_iter().filter(|r| !r.is_empty()).collect();
+        ranges.sort_by_key(|r| *r.start());
+
+        let mut spans = Vec::<RangeInclusive<u64>>::with_capacity(ranges.len());
+        for r in ranges {
+            match spans.last_mut() {
+                Some(last) if *r.start() <= last.end().saturating_add(1) => {
+                    // Overlapping or adjacent - combine into one span
+                    let end = (*last.end()).max(*r.end());
+                    *last = *last.start()..=end;
+                }
+                _ => spans.push(r),
+            }
+        }
+        IntervalSet { spans }
+    }
+
+    /// Add every number in `range` to the set
+    pub fn insert(&mut self, range: RangeInclusive<u64>) {
+        if range.is_empty() {
+            return;
+        }
+        let (start, end) = range.into_inner();
+
+        // Spans before `first` end too early to touch the new range, and spans from `last` on
+        // start too late
+        let first = self
+            .spans
+            .partition_point(|s| s.end().saturating_add(1) < start);
+        let last = self
+            .spans
+            .partition_point(|s| *s.start() <= end.saturating_add(1));
+
+        let mut merged = start..=end;
+        if first < last {
+            merged = start.min(*self.spans[first].start())..=end.max(*self.spans[last - 1].end());
+        }
+        self.spans.splice(first..last, [merged]);
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        self.span_containing(value).is_some()
+    }
+
+    /// The span that `value` is in, if any
+    pub fn span_containing(&self, value: u64) -> Option<&RangeInclusive<u64>> {
+        let idx = self.spans.partition_point(|s| *s.end() < value);
+        self.spans.get(idx).filter(|s| *s.start() <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// How many numbers are in the set. This is a `u128` because the set can hold every `u64`.
+    pub fn coverage(&self) -> u128 {
+        self.spans
+            .iter()
+            .map(|s| u128::from(s.end() - s.start()) + 1)
+            .sum()
+    }
+
+    /// The disjoint spans making up the set, in ascending order
+    pub fn spans(&self) -> &[RangeInclusive<u64>] {
+        &self.spans
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        IntervalSet::merge(self.spans.iter().chain(&other.spans).cloned())
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut spans = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.spans.get(i), other.spans.get(j)) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                spans.push(start..=end);
+            }
+            // The span that ends first can't overlap anything else in the other set
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { spans }
+    }
+
+    /// Numbers in this set but not in `other`
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut spans = Vec::new();
+        let mut j = 0;
+        for span in &self.spans {
+            let mut start = *span.start();
+            let end = *span.end();
+
+            // Skip spans of `other` that end before this one starts
+            while j < other.spans.len() && *other.spans[j].end() < start {
+                j += 1;
+            }
+
+            let mut remaining = true;
+            let mut k = j;
+            while let Some(cut) = other.spans.get(k).filter(|cut| *cut.start() <= end) {
+                if *cut.start() > start {
+                    spans.push(start..=cut.start() - 1);
+                }
+                match cut.end().checked_add(1) {
+                    Some(next) if next <= end => start = next,
+                    _ => {
+                        remaining = false;
+                        break;
+                    }
+                }
+                k += 1;
+            }
+            if remaining {
+                spans.push(start..=end);
+            }
+        }
+        IntervalSet { spans }
+    }
+}
+
+/// Parse a `lower-upper` range, converting both bounds with `parse_bound`. Neither bound can have
+/// a sign, and the lower bound can't be greater than the upper one. Errors are messages for the
+/// caller to put in context, such as which entry or line the range came from.
+pub fn parse_range<T, F>(s: &str, parse_bound: F) -> Result<RangeInclusive<T>, &'static str>
+where
+    T: PartialOrd,
+    F: Fn(&str) -> Option<T>,
+{
+    let Some((lower, upper)) = s.split_once('-') else {
+        return Err("expected two numbers separated by a dash");
+    };
+
+    // Integer parsers accept a leading `+`, which isn't part of any range format
+    let parse = |bound: &str| {
+        (!bound.starts_with('+'))
+            .then(|| parse_bound(bound))
+            .flatten()
+    };
+    let lower = parse(lower).ok_or("lower bound is not a non-negative integer")?;
+    let upper = parse(upper).ok_or("upper bound is not a non-negative integer")?;
+
+    if lower > upper {
+        return Err("lower bound is greater than upper bound");
+    }
+    Ok(lower..=upper)
+}
+
+impl FromIterator<RangeInclusive<u64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u64>>>(iter: I) -> IntervalSet {
+        IntervalSet::merge(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Lcg;
+
+    #[test]
+    fn merge() {
+        let set = IntervalSet::merge([16..=20, 3..=5, 12..=18, 10..=14]);
+        assert_eq!(set.spans(), [3..=5, 10..=20]);
+        assert_eq!(set.coverage(), 14);
+
+        // Adjacent ranges are combined, empty ones are dropped
+        #[allow(clippy::reversed_empty_ranges)]
+        let set = IntervalSet::merge([1..=2, 3..=4, 9..=7]);
+        assert_eq!(set.spans(), [1..=4]);
+
+        let everything = IntervalSet::merge([0..=u64::MAX - 1, u64::MAX..=u64::MAX]);
+        assert_eq!(everything.spans(), [0..=u64::MAX]);
+        assert_eq!(everything.coverage(), 1 << 64);
+        assert!(IntervalSet::new().is_empty());
+    }
+
+    #[test]
+    fn insert_and_contains() {
+        let mut set = IntervalSet::new();
+        set.insert(10..=14);
+        set.insert(3..=5);
+        set.insert(16..=20);
+        assert_eq!(set.spans(), [3..=5, 10..=14, 16..=20]);
+        set.insert(15..=15);
+        assert_eq!(set.spans(), [3..=5, 10..=20]);
+        set.insert(0..=1);
+        set.insert(u64::MAX..=u64::MAX);
+        assert_eq!(set.spans(), [0..=1, 3..=5, 10..=20, u64::MAX..=u64::MAX]);
+
+        assert!(set.contains(4));
+        assert!(!set.contains(2));
+        assert!(set.contains(u64::MAX));
+        assert_eq!(set.span_containing(17), Some(&(10..=20)));
+        assert_eq!(set.span_containing(21), None);
+    }
+
+    #[test]
+    fn parse_ranges() {
+        let parse = |s| parse_range(s, |bound: &str| bound.parse::<u64>().ok());
+        assert_eq!(parse("3-5"), Ok(3..=5));
+        assert_eq!(parse("7-7"), Ok(7..=7));
+        assert_eq!(parse("0-18446744073709551615"), Ok(0..=u64::MAX));
+
+        assert_eq!(parse("35"), Err("expected two numbers separated by a dash"));
+        assert_eq!(
+            parse("+3-5"),
+            Err("lower bound is not a non-negative integer")
+        );
+        assert_eq!(
+            parse("-3-5"),
+            Err("lower bound is not a non-negative integer")
+        );
+        assert_eq!(
+            parse("3-+5"),
+            Err("upper bound is not a non-negative integer")
+        );
+        assert_eq!(
+            parse("1-2-3"),
+            Err("upper bound is not a non-negative integer")
+        );
+        assert_eq!(parse("5-3"), Err("lower bound is greater than upper bound"));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = IntervalSet::merge([0..=10, 20..=30]);
+        let b = IntervalSet::merge([5..=24, 30..=u64::MAX]);
+        assert_eq!(a.union(&b).spans(), [0..=u64::MAX]);
+        assert_eq!(a.intersection(&b).spans(), [5..=10, 20..=24, 30..=30]);
+        assert_eq!(a.difference(&b).spans(), [0..=4, 25..=29]);
+        assert_eq!(b.difference(&a).spans(), [11..=19, 31..=u64::MAX]);
+        assert!(a.difference(&a).is_empty());
+    }
+
+    /// Numbers below this are used by the randomized tests, so a set fits in a `u64` bitmask
+    const UNIVERSE: u64 = 64;
+
+    fn random_set(rng: &mut Lcg) -> (IntervalSet, u64) {
+        let mut ranges = Vec::new();
+        let mut mask = 0_u64;
+        for _ in 0..rng.below(UNIVERSE) % 6 {
+            let (a, b) = (rng.below(UNIVERSE), rng.below(UNIVERSE));
+            ranges.push(a..=b);
+            for value in a..=b {
+                mask |= 1 << value;
+            }
+        }
+        (IntervalSet::merge(ranges), mask)
+    }
+
+    fn as_mask(set: &IntervalSet) -> u64 {
+        // Every span is sorted, non-empty and separated from the next by at least one number
+        for pair in set.spans().windows(2) {
+            assert!(pair[0].end() + 1 < *pair[1].start(), "{set:?}");
+        }
+        assert!(set.spans().iter().all(|s| !s.is_empty()), "{set:?}");
+
+        let mask = (0..UNIVERSE)
+            .filter(|&value| set.contains(value))
+            .fold(0_u64, |mask, value| mask | 1 << value);
+        assert_eq!(set.coverage(), u128::from(mask.count_ones()), "{set:?}");
+        mask
+    }
+
+    #[test]
+    fn matches_bitmasks() {
+        let mut rng = Lcg::new(5);
+        for _ in 0..2000 {
+            let (a, a_mask) = random_set(&mut rng);
+            let (b, b_mask) = random_set(&mut rng);
+
+            assert_eq!(as_mask(&a), a_mask);
+            assert_eq!(as_mask(&a.union(&b)), a_mask | b_mask);
+            assert_eq!(as_mask(&a.intersection(&b)), a_mask & b_mask);
+            assert_eq!(as_mask(&a.difference(&b)), a_mask & !b_mask);
+
+            // Inserting spans one at a time gives the same set as merging them all at once
+            let mut inserted = a.clone();
+            for span in b.spans() {
+                inserted.insert(span.clone());
+            }
+            assert_eq!(inserted, a.union(&b));
+            assert_eq!(a.union(&b), b.union(&a));
+            assert_eq!(a.intersection(&b), b.intersection(&a));
+        }
+    }
+}
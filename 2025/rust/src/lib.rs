@@ -0,0 +1,71 @@
+//! Solutions to the 2025 Advent of Code puzzles.
+//!
+//! Each problem lives in its own module (`p1`, `p2`, ...) and implements [`Solution`]. Every
+//! solved day is listed in [`DAYS`], which the `rust` binary uses to run them. [`runner`],
+//! [`report`] and [`check`] contain the timing, output and answer checking used by that binary.
+
+pub mod p1;
+pub mod p2;
+pub mod p3;
+pub mod p4;
+pub mod p5;
+pub mod p6;
+
+pub mod bigint;
+pub mod grid;
+pub mod interval;
+
+pub mod error;
+pub use error::AocError;
+
+pub mod input;
+pub use input::{InputError, InputSource};
+
+pub mod solution;
+pub use solution::{Day, Solution};
+
+pub mod check;
+pub mod report;
+pub mod runner;
+
+#[cfg(test)]
+mod test_support;
+
+/// Year of the puzzles solved in this crate
+pub const YEAR: u16 = 2025;
+
+/// Every solved day, in order
+pub static DAYS: &[Day] = &[
+    Day::new::<p1::Problem>(YEAR, 1),
+    Day::new::<p2::Problem>(YEAR, 2),
+    Day::new::<p3::Problem>(YEAR, 3),
+    Day::new::<p4::Problem>(YEAR, 4),
+    Day::new::<p5::Problem>(YEAR, 5),
+    Day::new::<p6::Problem>(YEAR, 6),
+];
+
+/// Look up the solution registered for `day` of `year`
+pub fn find_day(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry() {
+        for (idx, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.year, YEAR);
+            assert_eq!(
+                day.day as usize,
+                idx + 1,
+                "Days must be registered in order"
+            );
+            assert!(std::ptr::eq(find_day(day.year, day.day).unwrap(), day));
+        }
+        assert!(find_day(YEAR, 0).is_none());
+        assert!(find_day(YEAR, DAYS.len() as u8 + 1).is_none());
+        assert!(find_day(YEAR - 1, 1).is_none());
+    }
+}
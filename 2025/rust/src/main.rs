@@ -1,16 +1,72 @@
-mod aoc;
-
-fn main() {
-    aoc::p1_1();
-    aoc::p1_2();
-    aoc::p2_1();
-    aoc::p2_2();
-    aoc::p3_1();
-    aoc::p3_2();
-    aoc::p4_1();
-    aoc::p4_2();
-    aoc::p5_1();
-    aoc::p5_2();
-    aoc::p6_1();
-    aoc::p6_2();
+mod cli;
+
+use aoc::report::{self, Format};
+use aoc::{check, runner};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let solved: Vec<u8> = aoc::DAYS
+        .iter()
+        .filter(|day| day.year == aoc::YEAR)
+        .map(|day| day.day)
+        .collect();
+
+    let options = match cli::parse_args(std::env::args().skip(1), &solved) {
+        Ok(cli::Command::Run(options)) => options,
+        Ok(cli::Command::Help) => {
+            println!("{}", cli::USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("ERROR: {e}\n\n{}", cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let answers = if options.check {
+        match check::find_answers().and_then(|path| check::Answers::load(&path)) {
+            Ok(answers) => Some(answers),
+            Err(e) => {
+                eprintln!("ERROR: {e}");
+                return ExitCode::from(2);
+            }
+        }
+    } else {
+        None
+    };
+
+    let selected = runner::parts_by_day(options.selections.iter().map(|s| (s.problem, s.part)));
+    let mut reports = Vec::with_capacity(selected.len());
+    for (problem, parts) in selected {
+        let day = aoc::find_day(aoc::YEAR, problem)
+            .expect("Selections are validated against the registry");
+
+        let report = runner::run_day(
+            day,
+            &parts,
+            options.bench.unwrap_or(1),
+            &options.input,
+            options.explain,
+        );
+        if answers.is_none() && options.bench.is_none() && options.format == Format::Text {
+            report::print_answers(&report, options.time);
+        }
+        reports.push(report);
+    }
+
+    if let Some(answers) = answers {
+        return match check::print_check(&reports, &answers) {
+            true => ExitCode::SUCCESS,
+            false => ExitCode::FAILURE,
+        };
+    }
+
+    match options.format {
+        Format::Text if options.bench.is_some() => report::print_bench(&reports),
+        Format::Text => {}
+        Format::Json => report::print_json(&reports),
+        Format::Csv => report::print_csv(&reports),
+    }
+
+    ExitCode::SUCCESS
 }
@@ -1,41 +1,46 @@
 //! Problem 1: https://adventofcode.com/2025/day/1
 
+use crate::{AocError, Solution};
+
 const STARTING_POSITION: u32 = 50;
 const NUM_POSITIONS: u32 = 100;
 
 #[derive(Debug, Copy, Clone)]
-enum TurnDirection {
+pub enum TurnDirection {
     Left,
     Right,
 }
 
 #[derive(Debug, Copy, Clone)]
-struct DialTurn {
-    direction: TurnDirection,
-    amount: u32,
+pub struct DialTurn {
+    pub direction: TurnDirection,
+    pub amount: u32,
 }
 
 impl DialTurn {
-    fn from_instruction(instruction: &str) -> DialTurn {
-        let lazy_error = || format!("Invalid instruction: {instruction}");
-
+    /// Parse an instruction such as `L68` or `R14`
+    pub fn from_instruction(instruction: &str) -> Result<DialTurn, AocError> {
         let mut chars = instruction.chars();
 
         // Left or right? Read the first character
-        let direction_char = chars.next().expect(lazy_error().as_str());
-        let direction = match direction_char {
-            'L' => TurnDirection::Left,
-            'R' => TurnDirection::Right,
-            _ => panic!("{}", lazy_error().as_str()),
+        let direction = match chars.next() {
+            Some('L') => TurnDirection::Left,
+            Some('R') => TurnDirection::Right,
+            Some(_) => {
+                return Err(
+                    AocError::parse("Expected the direction to be L or R", instruction)
+                        .at_column(1),
+                );
+            }
+            None => return Err(AocError::parse("Empty instruction", instruction)),
         };
 
         // The rest of the instruction should just be a number
-        let amount: u32 = chars
-            .collect::<String>()
-            .parse()
-            .expect(lazy_error().as_str());
+        let amount: u32 = chars.as_str().parse().map_err(|e| {
+            AocError::parse(format!("Invalid turn amount: {e}"), instruction).at_column(2)
+        })?;
 
-        DialTurn { direction, amount }
+        Ok(DialTurn { direction, amount })
     }
 }
 
@@ -72,16 +77,6 @@ fn turn_dial(dial: u32, password: &mut u32, turn: &DialTurn, count_passing: bool
     }
 }
 
-/// Read turns from the given input file
-fn get_turns() -> Vec<DialTurn> {
-    let input = super::input_to_string(1, 1);
-
-    input
-        .lines()
-        .map(|line| DialTurn::from_instruction(line))
-        .collect()
-}
-
 /// Solve the puzzle with `turns` parsed from text input. `count_passing` controls whether part 2 is
 /// being solved or not.
 fn solve(turns: &Vec<DialTurn>, count_passing: bool) -> u32 {
@@ -103,14 +98,30 @@ fn solve(turns: &Vec<DialTurn>, count_passing: bool) -> u32 {
     password
 }
 
-/// Problem 1 Part 1
-pub fn p1_1() {
-    println!("Problem 01 Part 1: {}", solve(&get_turns(), false));
-}
+pub struct Problem;
+
+impl Solution for Problem {
+    type Parsed = Vec<DialTurn>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Vec<DialTurn>, AocError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                DialTurn::from_instruction(line.trim()).map_err(|e| e.at_line(idx + 1))
+            })
+            .collect()
+    }
 
-/// Problem 1 Part 2
-pub fn p1_2() {
-    println!("Problem 01 Part 2: {}", solve(&get_turns(), true));
+    fn part_1(turns: &Vec<DialTurn>) -> Result<u32, AocError> {
+        Ok(solve(turns, false))
+    }
+
+    fn part_2(turns: &Vec<DialTurn>) -> Result<u32, AocError> {
+        Ok(solve(turns, true))
+    }
 }
 
 #[cfg(test)]
@@ -122,30 +133,68 @@ mod tests {
         "L68", "L30", "R48", "L5", "R60", "L55", "L1", "L99", "R14", "L82",
     ];
 
+    fn turn(instruction: &str) -> DialTurn {
+        DialTurn::from_instruction(instruction).unwrap()
+    }
+
     #[test]
     fn parse_instructions() {
-        let one_digit = DialTurn::from_instruction("L5");
+        let one_digit = turn("L5");
         assert!(matches!(one_digit.direction, TurnDirection::Left));
         assert_eq!(one_digit.amount, 5);
 
-        let two_digits = DialTurn::from_instruction("R42");
+        let two_digits = turn("R42");
         assert!(matches!(two_digits.direction, TurnDirection::Right));
         assert_eq!(two_digits.amount, 42);
 
-        let three_digits = DialTurn::from_instruction("L123");
+        let three_digits = turn("L123");
         assert!(matches!(three_digits.direction, TurnDirection::Left));
         assert_eq!(three_digits.amount, 123);
 
-        let four_digits = DialTurn::from_instruction("R9999");
+        let four_digits = turn("R9999");
         assert!(matches!(four_digits.direction, TurnDirection::Right));
         assert_eq!(four_digits.amount, 9999);
     }
 
+    #[test]
+    fn invalid_instructions() {
+        for instruction in ["", "X5", "l5", "L", "L-5", "R5.5", "R 5", "L99999999999"] {
+            assert!(
+                matches!(
+                    DialTurn::from_instruction(instruction),
+                    Err(AocError::Parse { .. })
+                ),
+                "{instruction:?} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_errors_name_the_line() {
+        let error = Problem::parse("L68\nL30\nR4x8\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Line 3, column 2: Invalid turn amount: invalid digit found in string: \"R4x8\""
+        );
+
+        let error = Problem::parse("L68\n\nQ30\n").err().unwrap();
+        assert!(matches!(
+            error,
+            AocError::Parse {
+                line: Some(3),
+                column: Some(1),
+                ..
+            }
+        ));
+
+        assert_eq!(Problem::parse("L68\nR30\n").unwrap().len(), 2);
+    }
+
     #[test]
     fn example() {
         let turns: Vec<DialTurn> = EXAMPLE
             .iter()
-            .map(|instruction| DialTurn::from_instruction(instruction))
+            .map(|instruction| turn(instruction))
             .collect();
         assert_eq!(solve(&turns, false), 3);
         assert_eq!(solve(&turns, true), 6);
@@ -154,12 +203,12 @@ mod tests {
     #[test]
     fn large_spins() {
         let turns = vec![
-            DialTurn::from_instruction("L25"),  // 25
-            DialTurn::from_instruction("R75"),  // 0
-            DialTurn::from_instruction("L525"), // 75 (pass 5 times)
-            DialTurn::from_instruction("R750"), // 25 (pass 8 times)
-            DialTurn::from_instruction("L15"),  // 40
-            DialTurn::from_instruction("R999"), // 39 (pass 10 times)
+            turn("L25"),  // 25
+            turn("R75"),  // 0
+            turn("L525"), // 75 (pass 5 times)
+            turn("R750"), // 25 (pass 8 times)
+            turn("L15"),  // 40
+            turn("R999"), // 39 (pass 10 times)
         ];
         assert_eq!(solve(&turns, false), 1);
         assert_eq!(solve(&turns, true), 24);
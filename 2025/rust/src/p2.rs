@@ -0,0 +1,878 @@
+//! Problem 2: https://adventofcode.com/2025/day/2
+
+use crate::interval::parse_range;
+use crate::{AocError, Solution};
+use std::any::type_name;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+/// Unsigned integer types that IDs can be stored in
+pub trait Unsigned:
+    Copy
+    + Ord
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+
+    /// Convert a value no larger than 36, such as a digit or a radix
+    fn from_small(value: u32) -> Self;
+    /// The inverse of [`Unsigned::from_small`]
+    fn to_small(self) -> u32;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, std::num::ParseIntError>;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl Unsigned for $t {
+            const ZERO: $t = 0;
+            const ONE: $t = 1;
+            const MAX: $t = <$t>::MAX;
+
+            fn from_small(value: u32) -> $t {
+                value as $t
+            }
+
+            fn to_small(self) -> u32 {
+                self as u32
+            }
+
+            fn checked_add(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn from_str_radix(s: &str, radix: u32) -> Result<$t, std::num::ParseIntError> {
+                <$t>::from_str_radix(s, radix)
+            }
+        }
+    )*};
+}
+
+impl_unsigned!(u32, u64, u128);
+
+/// Base that IDs are written in, from 2 to 36. Digits past 9 are the letters a to z.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Radix(u32);
+
+impl Radix {
+    /// The base used by the puzzle
+    pub const DECIMAL: Radix = Radix(10);
+
+    pub fn new(radix: u32) -> Result<Radix, AocError> {
+        if !(2..=36).contains(&radix) {
+            return Err(AocError::invalid_state(format!(
+                "Radix {radix} is not between 2 and 36"
+            )));
+        }
+        Ok(Radix(radix))
+    }
+
+    pub fn get(self) -> u32 {
+        self.0
+    }
+
+    fn base<T: Unsigned>(self) -> T {
+        T::from_small(self.0)
+    }
+
+    /// `radix^exponent`, or `None` if it doesn't fit in `T`
+    fn pow<T: Unsigned>(self, exponent: u64) -> Option<T> {
+        (0..exponent).try_fold(T::ONE, |acc, _| acc.checked_mul(self.base()))
+    }
+
+    /// Largest number with `len` digits, or `T::MAX` if that doesn't fit
+    fn max_with_digits<T: Unsigned>(self, len: u64) -> T {
+        self.pow(len).map_or(T::MAX, |pow: T| pow - T::ONE)
+    }
+
+    /// Number of digits needed to write `num`. Zero has one digit.
+    pub fn digit_count<T: Unsigned>(self, num: T) -> u64 {
+        let base = self.base();
+        let (mut num, mut count) = (num, 1);
+        while num >= base {
+            num = num / base;
+            count += 1;
+        }
+        count
+    }
+
+    /// Write `num` in this radix
+    pub fn format<T: Unsigned>(self, num: T) -> String {
+        let base = self.base();
+        let mut digits = Vec::new();
+        let mut num = num;
+        loop {
+            let digit = (num % base).to_small();
+            digits.push(char::from_digit(digit, self.0).expect("remainder is below the radix"));
+            num = num / base;
+            if num == T::ZERO {
+                break;
+            }
+        }
+        digits.iter().rev().collect()
+    }
+
+    /// The number that turns a `unit_len`-digit unit into a `len`-digit number by repeating it,
+    /// e.g. 1001001 for a unit of 3 decimal digits repeated to 9 digits. Repeating `u` gives
+    /// `u * repunit`. `None` if the repunit doesn't fit in `T`, in which case neither does any
+    /// number made by repeating a unit.
+    fn repunit<T: Unsigned>(self, len: u64, unit_len: u64) -> Option<T> {
+        let step: T = self.pow(unit_len)?;
+        (1..len / unit_len).try_fold(T::ONE, |acc, _| acc.checked_mul(step)?.checked_add(T::ONE))
+    }
+}
+
+/// Parse comma-separated `lower-upper` entries into inclusive ranges. Errors name the 1-based
+/// index of the offending entry.
+pub fn parse_ranges(input: &str) -> Result<Vec<RangeInclusive<u64>>, AocError> {
+    parse_ranges_in(input, Radix::DECIMAL)
+}
+
+/// [`parse_ranges`] for IDs of any width, written in `radix`
+pub fn parse_ranges_in<T: Unsigned>(
+    input: &str,
+    radix: Radix,
+) -> Result<Vec<RangeInclusive<T>>, AocError> {
+    let mut ranges = Vec::new();
+
+    for (idx, entry) in input.split(',').enumerate() {
+        let entry = entry.trim();
+        let error =
+            |message: &str| AocError::parse(format!("Range entry {}: {message}", idx + 1), entry);
+
+        let range = parse_range(entry, |bound| T::from_str_radix(bound, radix.get()).ok())
+            .map_err(error)?;
+        ranges.push(range);
+    }
+
+    Ok(ranges)
+}
+
+/// How many times the unit of a matching number must be repeated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repeats {
+    /// Exactly twice, as in part 1
+    Twice,
+    /// Two or more times, as in part 2
+    AtLeastTwice,
+}
+
+impl Repeats {
+    /// Lengths of the units that can be repeated to make a `len`-digit number
+    fn unit_lengths(self, len: u64) -> Vec<u64> {
+        match self {
+            Repeats::Twice if len.is_multiple_of(2) => vec![len / 2],
+            Repeats::Twice => Vec::new(),
+            Repeats::AtLeastTwice => (1..len).filter(|&k| len.is_multiple_of(k)).collect(),
+        }
+    }
+
+    /// Whether `num`, written in `radix`, repeats a unit as required
+    pub fn matches<T: Unsigned>(self, num: T, radix: Radix) -> bool {
+        let len = radix.digit_count(num);
+        self.unit_lengths(len).into_iter().any(|unit_len| {
+            radix
+                .repunit(len, unit_len)
+                .is_some_and(|repunit| num % repunit == T::ZERO)
+        })
+    }
+}
+
+/// Units of `unit_len` digits which, repeated to `len` digits, land in `lower..=upper`. `None` if
+/// there are none.
+fn units<T: Unsigned>(
+    lower: T,
+    upper: T,
+    len: u64,
+    unit_len: u64,
+    radix: Radix,
+) -> Option<(T, T, T)> {
+    let repunit = radix.repunit(len, unit_len)?;
+    let ceil = lower / repunit
+        + if lower % repunit == T::ZERO {
+            T::ZERO
+        } else {
+            T::ONE
+        };
+    let first = radix.pow::<T>(unit_len - 1)?.max(ceil);
+    let last = radix.max_with_digits::<T>(unit_len).min(upper / repunit);
+    (first <= last).then_some((first, last, repunit))
+}
+
+/// Sum of every `len`-digit number in `lower..=upper` made by repeating a `unit_len`-digit unit,
+/// or `None` if it overflows. These are the multiples of the repunit, so this is an arithmetic
+/// series.
+fn series_sum<T: Unsigned>(lower: T, upper: T, len: u64, unit_len: u64, radix: Radix) -> Option<T> {
+    let Some((first, last, repunit)) = units(lower, upper, len, unit_len, radix) else {
+        return Some(T::ZERO);
+    };
+    // The repunit is at least 3, so `first + last` can't overflow. Halving whichever of the count
+    // and `first + last` is even keeps every intermediate value below the result.
+    let count = last - first + T::ONE;
+    let two = T::from_small(2);
+    let units_sum = if count % two == T::ZERO {
+        (count / two).checked_mul(first + last)?
+    } else {
+        count.checked_mul((first + last) / two)?
+    };
+    repunit.checked_mul(units_sum)
+}
+
+/// Sum of the `len`-digit numbers in `lower..=upper` that repeat a unit as `repeats` requires, or
+/// `None` if it overflows.
+///
+/// For part 2 a number with a shorter unit also repeats every longer unit that is a multiple of
+/// it, so summing each unit length would count it more than once. This is inclusion-exclusion
+/// over the divisors of `len`: the sum for each unit length, minus the numbers whose shortest unit
+/// is a divisor of it, gives the numbers whose shortest unit is exactly that long. Every
+/// intermediate value is part of the final sum, so nothing overflows unless the result does.
+fn sum_with_length<T: Unsigned>(
+    lower: T,
+    upper: T,
+    len: u64,
+    repeats: Repeats,
+    radix: Radix,
+) -> Option<T> {
+    match repeats {
+        Repeats::Twice => repeats
+            .unit_lengths(len)
+            .into_iter()
+            .try_fold(T::ZERO, |acc, unit_len| {
+                acc.checked_add(series_sum(lower, upper, len, unit_len, radix)?)
+            }),
+        Repeats::AtLeastTwice => {
+            let mut shortest: Vec<(u64, T)> = Vec::new();
+            for unit_len in repeats.unit_lengths(len) {
+                let sum = series_sum(lower, upper, len, unit_len, radix)?;
+                let shorter = shortest
+                    .iter()
+                    .filter(|(shorter_len, _)| unit_len.is_multiple_of(*shorter_len))
+                    .fold(T::ZERO, |acc, &(_, sum)| acc + sum);
+                shortest.push((unit_len, sum - shorter));
+            }
+            shortest
+                .into_iter()
+                .try_fold(T::ZERO, |acc, (_, sum)| acc.checked_add(sum))
+        }
+    }
+}
+
+/// Split `range` into runs of numbers with the same digit count
+fn by_length<T: Unsigned>(
+    range: &RangeInclusive<T>,
+    radix: Radix,
+) -> impl Iterator<Item = (T, T, u64)> + use<T> {
+    let (lower, upper) = (*range.start(), *range.end());
+    (radix.digit_count(lower)..=radix.digit_count(upper))
+        .filter(move |_| lower <= upper)
+        .map(move |len| {
+            let shortest = radix.pow(len - 1).expect("shorter than a number that fits");
+            let lower = lower.max(shortest);
+            let upper = upper.min(radix.max_with_digits(len));
+            (lower, upper, len)
+        })
+}
+
+/// Sum of the numbers in `range`, written in `radix`, that repeat a unit as `repeats` requires.
+/// This takes time that only depends on the number of digits in the bounds.
+pub fn sum_repeated<T: Unsigned>(
+    range: &RangeInclusive<T>,
+    repeats: Repeats,
+    radix: Radix,
+) -> Result<T, AocError> {
+    by_length(range, radix)
+        .try_fold(T::ZERO, |acc, (lower, upper, len)| {
+            acc.checked_add(sum_with_length(lower, upper, len, repeats, radix)?)
+        })
+        .ok_or_else(|| {
+            AocError::invalid_state(format!(
+                "Sum of repeated IDs from {} to {} doesn't fit in a {}",
+                range.start(),
+                range.end(),
+                type_name::<T>()
+            ))
+        })
+}
+
+/// A number made by repeating a unit, e.g. 824824824 is 824 repeated 3 times
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<T> {
+    pub number: T,
+    /// The shortest unit for part 2, or the half of the number for part 1
+    pub unit: T,
+    pub repeats: u64,
+}
+
+impl<T: Unsigned> Match<T> {
+    /// The match written in `radix`, e.g. `824824824 = "824" × 3`
+    pub fn describe(&self, radix: Radix) -> String {
+        format!(
+            "{} = \"{}\" × {}",
+            radix.format(self.number),
+            radix.format(self.unit),
+            self.repeats
+        )
+    }
+}
+
+/// Every number in `range`, written in `radix`, that repeats a unit as `repeats` requires, in
+/// ascending order
+pub fn repeated_matches<T: Unsigned>(
+    range: &RangeInclusive<T>,
+    repeats: Repeats,
+    radix: Radix,
+) -> Vec<Match<T>> {
+    let mut matches = Vec::new();
+    for (lower, upper, len) in by_length(range, radix) {
+        for unit_len in repeats.unit_lengths(len) {
+            let Some((first, last, repunit)) = units(lower, upper, len, unit_len, radix) else {
+                continue;
+            };
+            let mut unit = first;
+            loop {
+                // Skip units that are repetitions themselves - they are listed with their own
+                // shorter unit, which keeps every number unique
+                if repeats == Repeats::Twice || !Repeats::AtLeastTwice.matches(unit, radix) {
+                    matches.push(Match {
+                        number: unit * repunit,
+                        unit,
+                        repeats: len / unit_len,
+                    });
+                }
+                if unit == last {
+                    break;
+                }
+                unit = unit + T::ONE;
+            }
+        }
+    }
+    matches.sort_unstable_by_key(|m| m.number);
+    matches
+}
+
+/// The numbers from [`repeated_matches`] without their units
+pub fn repeated_numbers<T: Unsigned>(
+    range: &RangeInclusive<T>,
+    repeats: Repeats,
+    radix: Radix,
+) -> Vec<T> {
+    repeated_matches(range, repeats, radix)
+        .into_iter()
+        .map(|m| m.number)
+        .collect()
+}
+
+/// The matches found in a single input range
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeExplanation<T> {
+    pub range: RangeInclusive<T>,
+    pub matches: Vec<Match<T>>,
+    /// Sum of the matches
+    pub subtotal: T,
+}
+
+impl<T: Unsigned> RangeExplanation<T> {
+    /// A heading line for the range followed by one indented line per match, written in `radix`
+    pub fn describe(&self, radix: Radix) -> String {
+        let mut s = format!(
+            "{}-{}: {} invalid ID{}, subtotal {}\n",
+            radix.format(*self.range.start()),
+            radix.format(*self.range.end()),
+            self.matches.len(),
+            if self.matches.len() == 1 { "" } else { "s" },
+            radix.format(self.subtotal)
+        );
+        for m in &self.matches {
+            s.push_str(&format!("  {}\n", m.describe(radix)));
+        }
+        s
+    }
+}
+
+/// The matches and subtotal for each of `ranges`, in input order
+pub fn explain<T: Unsigned>(
+    ranges: &[RangeInclusive<T>],
+    repeats: Repeats,
+    radix: Radix,
+) -> Result<Vec<RangeExplanation<T>>, AocError> {
+    ranges
+        .iter()
+        .map(|range| {
+            Ok(RangeExplanation {
+                range: range.clone(),
+                matches: repeated_matches(range, repeats, radix),
+                subtotal: sum_repeated(range, repeats, radix)?,
+            })
+        })
+        .collect()
+}
+
+/// Sum of the matching IDs in each of `ranges`. The ranges aren't merged into an
+/// [`IntervalSet`](crate::interval::IntervalSet) first, so an ID in two ranges is counted for each
+/// of them, the same as in the subtotals of [`explain`].
+fn total(ranges: &[RangeInclusive<u64>], repeats: Repeats) -> Result<u64, AocError> {
+    ranges.iter().try_fold(0_u64, |acc, range| {
+        let sum = sum_repeated(range, repeats, Radix::DECIMAL)?;
+        acc.checked_add(sum)
+            .ok_or_else(|| AocError::invalid_state("Sum of repeated IDs doesn't fit in a u64"))
+    })
+}
+
+fn part_1(ranges: &[RangeInclusive<u64>]) -> Result<u64, AocError> {
+    total(ranges, Repeats::Twice)
+}
+
+fn part_2(ranges: &[RangeInclusive<u64>]) -> Result<u64, AocError> {
+    total(ranges, Repeats::AtLeastTwice)
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Parsed = Vec<RangeInclusive<u64>>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Vec<RangeInclusive<u64>>, AocError> {
+        parse_ranges(input)
+    }
+
+    fn part_1(ranges: &Vec<RangeInclusive<u64>>) -> Result<u64, AocError> {
+        part_1(ranges)
+    }
+
+    fn part_2(ranges: &Vec<RangeInclusive<u64>>) -> Result<u64, AocError> {
+        part_2(ranges)
+    }
+
+    fn explain(ranges: &Vec<RangeInclusive<u64>>, part: u8) -> Option<Result<String, AocError>> {
+        let repeats = match part {
+            1 => Repeats::Twice,
+            _ => Repeats::AtLeastTwice,
+        };
+        let explanation = explain(ranges, repeats, Radix::DECIMAL).map(|explanations| {
+            explanations
+                .iter()
+                .map(|e| e.describe(Radix::DECIMAL))
+                .collect()
+        });
+        Some(explanation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Lcg;
+
+    // Example input given in https://adventofcode.com/2025/day/2
+    #[rustfmt::skip]
+    const EXAMPLE: &str = concat!(
+    "11-22,",
+    "95-115,",
+    "998-1012,",
+    "1188511880-1188511890,",
+    "222220-222224,",
+    "1698522-1698528,",
+    "446443-446449,",
+    "38593856-38593862,",
+    "565653-565659,",
+    "824824821-824824827,",
+    "2121212118-2121212124"
+    );
+
+    const DECIMAL: Radix = Radix::DECIMAL;
+
+    #[test]
+    fn parse_ranges() {
+        let ranges = super::parse_ranges(EXAMPLE).unwrap();
+        assert_eq!(ranges.len(), 11);
+        assert_eq!(ranges[0], 11..=22);
+        assert_eq!(ranges[1], 95..=115);
+        assert_eq!(ranges[2], 998..=1012);
+        assert_eq!(ranges[3], 1188511880..=1188511890);
+        assert_eq!(ranges[4], 222220..=222224);
+        assert_eq!(ranges[5], 1698522..=1698528);
+        assert_eq!(ranges[6], 446443..=446449);
+        assert_eq!(ranges[7], 38593856..=38593862);
+        assert_eq!(ranges[8], 565653..=565659);
+        assert_eq!(ranges[9], 824824821..=824824827);
+        assert_eq!(ranges[10], 2121212118..=2121212124);
+    }
+
+    #[test]
+    fn invalid_ranges() {
+        let invalid = [
+            ("11-22,95", 2),
+            ("11-22,95-115,x-1012", 3),
+            ("11--22", 1),
+            ("11-22,-5", 2),
+            ("11-+22", 1),
+            ("22-11", 1),
+            ("11-22,", 2),
+            ("0-18446744073709551616", 1),
+        ];
+        for (input, entry) in invalid {
+            let error = super::parse_ranges(input).err();
+            assert!(
+                matches!(&error, Some(AocError::Parse { message, .. })
+                    if message.starts_with(&format!("Range entry {entry}:"))),
+                "{input:?} should fail at entry {entry}, got {error:?}"
+            );
+        }
+
+        assert_eq!(
+            super::parse_ranges("22-11").err().unwrap().to_string(),
+            "Range entry 1: lower bound is greater than upper bound: \"22-11\""
+        );
+        assert_eq!(
+            super::parse_ranges("0-18446744073709551615\n").unwrap(),
+            vec![0..=u64::MAX]
+        );
+    }
+
+    #[test]
+    fn parse_other_radixes() {
+        let binary = parse_ranges_in::<u32>("101-1111,0-1", Radix::new(2).unwrap()).unwrap();
+        assert_eq!(binary, [5..=15, 0..=1]);
+
+        let hex = parse_ranges_in::<u128>("ff-1FF", Radix::new(16).unwrap()).unwrap();
+        assert_eq!(hex, [255..=511]);
+
+        let wide = parse_ranges_in::<u128>("0-18446744073709551616", DECIMAL).unwrap();
+        assert_eq!(wide, [0..=u128::from(u64::MAX) + 1]);
+
+        assert!(parse_ranges_in::<u64>("12-13", Radix::new(2).unwrap()).is_err());
+        assert!(Radix::new(1).is_err());
+        assert!(Radix::new(37).is_err());
+    }
+
+    #[test]
+    fn digit_count() {
+        assert_eq!(DECIMAL.digit_count(0_u64), 1);
+        assert_eq!(DECIMAL.digit_count(1_u64), 1);
+        assert_eq!(DECIMAL.digit_count(12_u64), 2);
+        assert_eq!(DECIMAL.digit_count(123_u64), 3);
+        assert_eq!(DECIMAL.digit_count(1234_u64), 4);
+        assert_eq!(DECIMAL.digit_count(12345_u64), 5);
+        assert_eq!(DECIMAL.digit_count(123456_u64), 6);
+        assert_eq!(DECIMAL.digit_count(1234567_u64), 7);
+        assert_eq!(DECIMAL.digit_count(12345678_u64), 8);
+        assert_eq!(DECIMAL.digit_count(123456789_u64), 9);
+        assert_eq!(DECIMAL.digit_count(1234567890_u64), 10);
+        assert_eq!(DECIMAL.digit_count(u64::MAX), 20);
+
+        assert_eq!(DECIMAL.digit_count(u128::MAX), 39);
+        assert_eq!(Radix::new(2).unwrap().digit_count(u64::MAX), 64);
+        assert_eq!(Radix::new(36).unwrap().digit_count(35_u32), 1);
+        assert_eq!(Radix::new(36).unwrap().digit_count(36_u32), 2);
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn doubled_numbers() {
+        let is_doubled_sequence = |num: u64| Repeats::Twice.matches(num, DECIMAL);
+        assert_eq!(false, is_doubled_sequence(0));
+        assert_eq!(false, is_doubled_sequence(10));
+        assert_eq!(false, is_doubled_sequence(u64::MAX));
+
+        assert_eq!(true, is_doubled_sequence(11));
+        assert_eq!(true, is_doubled_sequence(2222));
+        assert_eq!(true, is_doubled_sequence(9999999999));
+    }
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(
+            1227775554,
+            part_1(&super::parse_ranges(EXAMPLE).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(
+            4174379265,
+            part_2(&super::parse_ranges(EXAMPLE).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn overlapping_ranges() {
+        // 99 and 111 are in both ranges, and are counted for each of them like the subtotals
+        let ranges = super::parse_ranges("95-115,99-120").unwrap();
+        assert_eq!(part_1(&ranges).unwrap(), 2 * 99);
+        assert_eq!(part_2(&ranges).unwrap(), 2 * (99 + 111));
+
+        let subtotals: u64 = explain(&ranges, Repeats::AtLeastTwice, DECIMAL)
+            .unwrap()
+            .iter()
+            .map(|e| e.subtotal)
+            .sum();
+        assert_eq!(subtotals, part_2(&ranges).unwrap());
+    }
+
+    /// The original string-based checks, for digits written in any radix
+    fn stringified(digits: &str, repeats: Repeats) -> bool {
+        let len = digits.len();
+        let unit_lengths = match repeats {
+            Repeats::Twice => len / 2..=len / 2,
+            Repeats::AtLeastTwice => 1..=len / 2,
+        };
+        unit_lengths.into_iter().any(|unit_len| {
+            unit_len > 0
+                && len.is_multiple_of(unit_len)
+                && (repeats == Repeats::AtLeastTwice || len == 2 * unit_len)
+                && digits
+                    .as_bytes()
+                    .chunks(unit_len)
+                    .all(|c| c == &digits.as_bytes()[..unit_len])
+        })
+    }
+
+    #[test]
+    fn repeated_numbers() {
+        let is_repeated_sequence = |num: u64| Repeats::AtLeastTwice.matches(num, DECIMAL);
+        assert!(!is_repeated_sequence(0));
+        assert!(!is_repeated_sequence(7));
+        assert!(!is_repeated_sequence(1213));
+        assert!(is_repeated_sequence(11));
+        assert!(is_repeated_sequence(121212));
+        assert!(is_repeated_sequence(824824824));
+        assert!(is_repeated_sequence(11111111111111111111));
+
+        for num in (0..200_000).chain(u64::MAX - 1000..=u64::MAX) {
+            for repeats in [Repeats::Twice, Repeats::AtLeastTwice] {
+                assert_eq!(
+                    repeats.matches(num, DECIMAL),
+                    stringified(&num.to_string(), repeats),
+                    "{num} {repeats:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn listing() {
+        let range = 95..=115_u64;
+        assert_eq!(
+            super::repeated_numbers(&range, Repeats::Twice, DECIMAL),
+            [99]
+        );
+        assert_eq!(
+            super::repeated_numbers(&range, Repeats::AtLeastTwice, DECIMAL),
+            [99, 111]
+        );
+        assert_eq!(
+            super::repeated_numbers(&(1..=999_999_u64), Repeats::AtLeastTwice, DECIMAL)[..12],
+            [11, 22, 33, 44, 55, 66, 77, 88, 99, 111, 222, 333]
+        );
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 5..=4_u64;
+        assert!(super::repeated_numbers(&empty, Repeats::Twice, DECIMAL).is_empty());
+        assert_eq!(sum_repeated(&empty, Repeats::Twice, DECIMAL).unwrap(), 0);
+    }
+
+    /// `count` random ranges of up to 5000 numbers with lower bounds of various magnitudes
+    fn random_ranges(seed: u64, count: usize, max_digits: u32) -> Vec<RangeInclusive<u64>> {
+        let mut ranges = vec![0..=0, 1..=9, 10..=99, 0..=20_000, 1000..=1000, 1111..=1111];
+        let mut rng = Lcg::new(seed);
+        for _ in 0..count {
+            let state = rng.next_u64();
+            let start = (state >> 20) % 10_u64.pow(1 + (state % max_digits as u64) as u32);
+            let width = (state >> 40) % 5000;
+            ranges.push(start..=start + width);
+        }
+        ranges
+    }
+
+    fn check_brute_force<T: Unsigned>(range: RangeInclusive<T>, radix: Radix, repeats: Repeats)
+    where
+        RangeInclusive<T>: Iterator<Item = T>,
+        u128: From<T>,
+    {
+        let expected: Vec<T> = range
+            .clone()
+            .filter(|&num| stringified(&radix.format(num), repeats))
+            .collect();
+        assert_eq!(
+            super::repeated_numbers(&range, repeats, radix),
+            expected,
+            "{range:?} {repeats:?} in radix {}",
+            radix.get()
+        );
+        assert_eq!(
+            u128::from(sum_repeated(&range, repeats, radix).unwrap()),
+            expected.iter().map(|&num| u128::from(num)).sum::<u128>(),
+            "{range:?} {repeats:?} in radix {}",
+            radix.get()
+        );
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for range in random_ranges(7, 100, 9) {
+            for repeats in [Repeats::Twice, Repeats::AtLeastTwice] {
+                check_brute_force(range.clone(), DECIMAL, repeats);
+            }
+        }
+    }
+
+    #[test]
+    fn other_radixes() {
+        assert_eq!(Radix::new(2).unwrap().format(10_u32), "1010");
+        assert_eq!(Radix::new(36).unwrap().format(71_u64), "1z");
+        assert_eq!(DECIMAL.format(0_u128), "0");
+
+        // 0b1010 and 0b111 repeat, 0b1001 doesn't
+        let binary = Radix::new(2).unwrap();
+        assert!(Repeats::Twice.matches(10_u32, binary));
+        assert!(Repeats::AtLeastTwice.matches(7_u32, binary));
+        assert!(!Repeats::Twice.matches(7_u32, binary));
+        assert!(!Repeats::AtLeastTwice.matches(9_u32, binary));
+
+        for radix in [2, 3, 7, 16, 36] {
+            let radix = Radix::new(radix).unwrap();
+            for range in random_ranges(radix.get() as u64, 20, 6) {
+                for repeats in [Repeats::Twice, Repeats::AtLeastTwice] {
+                    check_brute_force(range.clone(), radix, repeats);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn widths() {
+        // The same answers whatever the integer is stored in
+        for range in random_ranges(11, 50, 9) {
+            let narrow = (*range.start() as u32)..=(*range.end() as u32);
+            let wide = u128::from(*range.start())..=u128::from(*range.end());
+            for repeats in [Repeats::Twice, Repeats::AtLeastTwice] {
+                let sum = sum_repeated(&range, repeats, DECIMAL).unwrap();
+                assert_eq!(
+                    u64::from(sum_repeated(&narrow, repeats, DECIMAL).unwrap()),
+                    sum
+                );
+                assert_eq!(
+                    sum_repeated(&wide, repeats, DECIMAL).unwrap(),
+                    u128::from(sum)
+                );
+            }
+        }
+
+        // Numbers past u64::MAX are only reachable in u128
+        let past_u64 = u128::from(u64::MAX)..=u128::from(u64::MAX) + 30_000_000_000;
+        assert_eq!(
+            super::repeated_numbers(&past_u64, Repeats::Twice, DECIMAL),
+            [
+                18_446_744_081_844_674_408_u128,
+                18_446_744_091_844_674_409,
+                18_446_744_101_844_674_410
+            ]
+        );
+        check_brute_force(
+            10_000_000_000_000_000_000_u128..=10_000_000_000_000_100_000,
+            DECIMAL,
+            Repeats::AtLeastTwice,
+        );
+        check_brute_force(
+            u128::MAX - 10_000..=u128::MAX,
+            Radix::new(2).unwrap(),
+            Repeats::AtLeastTwice,
+        );
+        assert!(Repeats::AtLeastTwice.matches(u128::MAX, Radix::new(2).unwrap()));
+        assert!(Repeats::Twice.matches(u128::MAX, Radix::new(16).unwrap()));
+    }
+
+    #[test]
+    fn wide_ranges() {
+        // 9 + 90 + 900 + ... doubled numbers with two to eighteen digits, summed per length
+        let pow10 = |k: u32| 10_u128.pow(k);
+        let every: u128 = (1..=9)
+            .map(|k| {
+                let (first, last) = (pow10(k - 1), pow10(k) - 1);
+                (pow10(k) + 1) * (first + last) * (last - first + 1) / 2
+            })
+            .sum();
+        assert_eq!(
+            sum_repeated(&(0..=10_u128.pow(18)), Repeats::Twice, DECIMAL).unwrap(),
+            every
+        );
+        let split = 10_u128.pow(19);
+        assert_eq!(
+            sum_repeated(&(0..=u128::from(u64::MAX)), Repeats::AtLeastTwice, DECIMAL).unwrap(),
+            sum_repeated(&(0..=split - 1), Repeats::AtLeastTwice, DECIMAL).unwrap()
+                + sum_repeated(
+                    &(split..=u128::from(u64::MAX)),
+                    Repeats::AtLeastTwice,
+                    DECIMAL
+                )
+                .unwrap()
+        );
+
+        // The sum over every u64 is past u64::MAX but fits in a u128
+        assert!(sum_repeated(&(0..=u64::MAX), Repeats::AtLeastTwice, DECIMAL).is_err());
+        assert!(part_2(&[0..=u64::MAX, 1..=2]).is_err());
+        assert!(sum_repeated(&(0..=u128::MAX), Repeats::AtLeastTwice, DECIMAL).is_err());
+    }
+
+    #[test]
+    fn explanations() {
+        let ranges = super::parse_ranges(EXAMPLE).unwrap();
+        let explanations = explain(&ranges, Repeats::AtLeastTwice, DECIMAL).unwrap();
+        assert_eq!(explanations.len(), ranges.len());
+        assert_eq!(
+            explanations.iter().map(|e| e.subtotal).sum::<u64>(),
+            4174379265
+        );
+
+        assert_eq!(
+            explanations[9].matches,
+            [Match {
+                number: 824824824,
+                unit: 824,
+                repeats: 3
+            }]
+        );
+        assert_eq!(
+            explanations[9].matches[0].describe(DECIMAL),
+            "824824824 = \"824\" × 3"
+        );
+        assert_eq!(
+            explanations[1].describe(DECIMAL),
+            "95-115: 2 invalid IDs, subtotal 210\n  99 = \"9\" × 2\n  111 = \"1\" × 3\n"
+        );
+        assert_eq!(
+            explanations[2].describe(DECIMAL),
+            "998-1012: 2 invalid IDs, subtotal 2009\n  999 = \"9\" × 3\n  1010 = \"10\" × 2\n"
+        );
+        assert_eq!(
+            explanations[5].describe(DECIMAL),
+            "1698522-1698528: 0 invalid IDs, subtotal 0\n"
+        );
+
+        // Part 1 always splits the number in half, even if a shorter unit would do
+        let doubled = explain(&[2222..=2222_u64], Repeats::Twice, DECIMAL).unwrap();
+        assert_eq!(doubled[0].matches[0].describe(DECIMAL), "2222 = \"22\" × 2");
+
+        let binary = Radix::new(2).unwrap();
+        let explanations = explain(&[9..=10_u32], Repeats::AtLeastTwice, binary).unwrap();
+        assert_eq!(
+            explanations[0].describe(binary),
+            "1001-1010: 1 invalid ID, subtotal 1010\n  1010 = \"10\" × 2\n"
+        );
+    }
+}
@@ -0,0 +1,589 @@
+//! Problem 3: https://adventofcode.com/2025/day/3
+
+use crate::bigint::BigUint;
+use crate::{AocError, Solution};
+
+fn ascii_char_to_digit(char: u8) -> Option<u8> {
+    if !char.is_ascii_digit() {
+        return None;
+    }
+    Some(char - b'0')
+}
+
+pub struct Bank {
+    batteries: Vec<u8>,
+}
+
+impl Bank {
+    /// Parse a non-empty line of digits
+    pub fn from_string(s: &str) -> Result<Bank, AocError> {
+        if s.is_empty() {
+            return Err(AocError::parse("Bank has no batteries", s));
+        }
+
+        // Go through characters rather than bytes so a non-ASCII character is reported whole, at
+        // the right column
+        let batteries = s
+            .chars()
+            .enumerate()
+            .map(|(idx, c)| {
+                u8::try_from(c)
+                    .ok()
+                    .and_then(ascii_char_to_digit)
+                    .ok_or_else(|| {
+                        AocError::parse("Character does not represent a digit", c)
+                            .at_column(idx + 1)
+                    })
+            })
+            .collect::<Result<Vec<u8>, AocError>>()?;
+
+        Ok(Bank { batteries })
+    }
+
+    pub fn batteries(&self) -> &[u8] {
+        &self.batteries
+    }
+
+    /// Largest number that can be formed by turning on `num_batteries` batteries, keeping their
+    /// order. Any number of batteries can be turned on without overflowing.
+    pub fn joltage(&self, num_batteries: usize) -> Result<BigUint, AocError> {
+        Ok(self.joltage_of(&self.select(num_batteries)?))
+    }
+
+    /// Smallest number that can be formed by turning on `num_batteries` batteries, keeping their
+    /// order. The first battery turned on is never a 0 unless it is the only one, so the joltage
+    /// always has `num_batteries` digits.
+    pub fn min_joltage(&self, num_batteries: usize) -> Result<BigUint, AocError> {
+        Ok(self.joltage_of(&self.select_smallest(num_batteries)?))
+    }
+
+    /// The number formed by the batteries of `selection`
+    pub fn joltage_of(&self, selection: &Selection) -> BigUint {
+        let digits: Vec<u8> = selection
+            .indices
+            .iter()
+            .map(|&idx| self.batteries[idx])
+            .collect();
+        BigUint::from_digits(&digits)
+    }
+
+    /// The batteries that give the largest joltage from `num_batteries` of them. Of the
+    /// selections that give that joltage, this is the one that turns on the earliest batteries.
+    pub fn select(&self, num_batteries: usize) -> Result<Selection, AocError> {
+        self.check_count(num_batteries)?;
+
+        let mut to_remove = self.batteries.len() - num_batteries;
+        let mut stack: Vec<usize> = Vec::with_capacity(self.batteries.len());
+
+        for (idx, &digit) in self.batteries.iter().enumerate() {
+            while let Some(&top) = stack.last() {
+                if to_remove == 0 || self.batteries[top] >= digit {
+                    break;
+                }
+                stack.pop();
+                to_remove -= 1;
+            }
+            stack.push(idx);
+        }
+
+        // Keep only the first `num_batteries` digits to get exact length
+        stack.truncate(num_batteries);
+        Ok(Selection { indices: stack })
+    }
+
+    /// The batteries that give the smallest joltage from `num_batteries` of them, see
+    /// [`Bank::min_joltage`]. Ties are broken the same way as in [`Bank::select`].
+    pub fn select_smallest(&self, num_batteries: usize) -> Result<Selection, AocError> {
+        self.check_count(num_batteries)?;
+        let next = self.next_positions();
+        let len = self.batteries.len();
+
+        let mut indices = Vec::with_capacity(num_batteries);
+        let mut start = 0;
+        for remaining in (1..=num_batteries).rev() {
+            // The chosen battery has to leave enough batteries after it for the rest
+            let last = len - remaining;
+            let lowest = if indices.is_empty() && num_batteries > 1 {
+                1
+            } else {
+                0
+            };
+            let Some(idx) = (lowest..=9)
+                .filter_map(|digit| next[start][digit])
+                .find(|&idx| idx <= last)
+            else {
+                return Err(AocError::invalid_state(format!(
+                    "Every way of turning on {num_batteries} batteries starts with a 0."
+                )));
+            };
+            indices.push(idx);
+            start = idx + 1;
+        }
+        Ok(Selection { indices })
+    }
+
+    /// The selections of `num_batteries` batteries with the `count` largest joltages, largest
+    /// first. Each joltage appears once, chosen the same way as in [`Bank::select`], so there can
+    /// be fewer than `count` of them.
+    pub fn top_selections(
+        &self,
+        num_batteries: usize,
+        count: usize,
+    ) -> Result<Vec<Selection>, AocError> {
+        self.check_count(num_batteries)?;
+        let next = self.next_positions();
+        let mut found = Vec::new();
+        let mut prefix = Vec::with_capacity(num_batteries);
+        self.collect_largest(&next, num_batteries, count, &mut prefix, &mut found);
+        Ok(found)
+    }
+
+    /// Depth-first search over the digits of the joltage, largest first. Only the earliest
+    /// occurrence of each digit is tried, so no joltage is found twice, and every branch has at
+    /// least one complete selection, so the search stops as soon as it has `count` of them.
+    fn collect_largest(
+        &self,
+        next: &[[Option<usize>; 10]],
+        num_batteries: usize,
+        count: usize,
+        prefix: &mut Vec<usize>,
+        found: &mut Vec<Selection>,
+    ) {
+        if found.len() == count {
+            return;
+        }
+        let remaining = num_batteries - prefix.len();
+        if remaining == 0 {
+            found.push(Selection {
+                indices: prefix.clone(),
+            });
+            return;
+        }
+
+        let start = prefix.last().map_or(0, |&idx| idx + 1);
+        let last = self.batteries.len() - remaining;
+        for digit in (0..=9).rev() {
+            match next[start][digit] {
+                Some(idx) if idx <= last => {
+                    prefix.push(idx);
+                    self.collect_largest(next, num_batteries, count, prefix, found);
+                    prefix.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// For every position, where each digit next appears at or after it
+    fn next_positions(&self) -> Vec<[Option<usize>; 10]> {
+        let mut next = vec![[None; 10]; self.batteries.len() + 1];
+        for (idx, &digit) in self.batteries.iter().enumerate().rev() {
+            next[idx] = next[idx + 1];
+            next[idx][digit as usize] = Some(idx);
+        }
+        next
+    }
+
+    fn check_count(&self, num_batteries: usize) -> Result<(), AocError> {
+        if num_batteries > self.batteries.len() {
+            return Err(AocError::invalid_state(format!(
+                "Attempted to get joltage from {} batteries, but there are only {} in a pack.",
+                num_batteries,
+                self.batteries.len(),
+            )));
+        }
+        Ok(())
+    }
+
+    /// The bank's digits with those at `indices` wrapped in brackets, e.g. `[9][8]7654321111111`
+    pub fn highlight(&self, indices: &[usize]) -> String {
+        let mut s = String::with_capacity(self.batteries.len() + 2 * indices.len());
+        for (idx, &digit) in self.batteries.iter().enumerate() {
+            let digit = char::from(b'0' + digit);
+            if indices.contains(&idx) {
+                s.extend(['[', digit, ']']);
+            } else {
+                s.push(digit);
+            }
+        }
+        s
+    }
+}
+
+/// Batteries turned on in a bank
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    /// Positions of the chosen batteries in the bank, in ascending order
+    pub indices: Vec<usize>,
+}
+
+/// Which numbers of batteries are allowed in the banks of an input
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BankLengths {
+    /// Every bank has as many batteries as the first one
+    #[default]
+    Uniform,
+    /// Every bank has exactly this many batteries
+    Exactly(usize),
+    /// Banks can have any number of batteries
+    Any,
+}
+
+/// Parse one bank per line, skipping empty lines, and check their lengths against `lengths`
+pub fn get_banks(input: &str, lengths: BankLengths) -> Result<Vec<Bank>, AocError> {
+    let mut banks = Vec::<Bank>::new();
+    let mut expected = match lengths {
+        BankLengths::Exactly(len) => Some(len),
+        BankLengths::Uniform | BankLengths::Any => None,
+    };
+
+    for (idx, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let bank = Bank::from_string(line).map_err(|e| e.at_line(idx + 1))?;
+
+        let len = bank.batteries.len();
+        match expected {
+            Some(expected) if len != expected => {
+                return Err(AocError::parse(
+                    format!("Expected {expected} characters, found {len}"),
+                    line,
+                )
+                .at_line(idx + 1));
+            }
+            None if lengths == BankLengths::Uniform => expected = Some(len),
+            _ => {}
+        }
+        banks.push(bank);
+    }
+
+    Ok(banks)
+}
+
+fn total_joltage(banks: &[Bank], num_batteries: usize) -> Result<BigUint, AocError> {
+    let mut acc = BigUint::zero();
+    for bank in banks {
+        acc += &bank.joltage(num_batteries)?;
+    }
+    Ok(acc)
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Parsed = Vec<Bank>;
+    type Answer = BigUint;
+
+    fn parse(input: &str) -> Result<Vec<Bank>, AocError> {
+        get_banks(input, BankLengths::Uniform)
+    }
+
+    fn part_1(banks: &Vec<Bank>) -> Result<BigUint, AocError> {
+        total_joltage(banks, 2)
+    }
+
+    fn part_2(banks: &Vec<Bank>) -> Result<BigUint, AocError> {
+        total_joltage(banks, 12)
+    }
+
+    fn explain(banks: &Vec<Bank>, part: u8) -> Option<Result<String, AocError>> {
+        let num_batteries = if part == 1 { 2 } else { 12 };
+        let explanation = banks
+            .iter()
+            .map(|bank| {
+                let selection = bank.select(num_batteries)?;
+                Ok(format!(
+                    "{} = {}\n",
+                    bank.highlight(&selection.indices),
+                    bank.joltage_of(&selection)
+                ))
+            })
+            .collect();
+        Some(explanation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Lcg;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        987654321111111
+        811111111111119
+        234234234234278
+        818181911112111
+    "};
+
+    #[test]
+    fn parse_banks() {
+        let banks = get_banks(EXAMPLE, BankLengths::Uniform).unwrap();
+        assert_eq!(banks.len(), 4);
+        assert_eq!(
+            banks[0].batteries,
+            [9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1]
+        );
+        assert_eq!(
+            banks[1].batteries,
+            [8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9]
+        );
+        assert_eq!(
+            banks[2].batteries,
+            [2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8]
+        );
+        assert_eq!(
+            banks[3].batteries,
+            [8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = get_banks("12345\n12a45\n", BankLengths::Uniform)
+            .err()
+            .unwrap();
+        assert!(matches!(
+            &error,
+            AocError::Parse {
+                line: Some(2),
+                column: Some(3),
+                text,
+                ..
+            } if text == "a"
+        ));
+
+        let error = get_banks("12345\n1234\n", BankLengths::Uniform)
+            .err()
+            .unwrap();
+        assert!(matches!(error, AocError::Parse { line: Some(2), .. }));
+
+        let error = get_banks("12345\n12345\n", BankLengths::Exactly(4))
+            .err()
+            .unwrap();
+        assert!(matches!(error, AocError::Parse { line: Some(1), .. }));
+
+        assert!(Bank::from_string("").is_err());
+
+        let error = Bank::from_string("12é4").err().unwrap();
+        assert!(matches!(
+            &error,
+            AocError::Parse {
+                column: Some(3),
+                text,
+                ..
+            } if text == "é"
+        ));
+    }
+
+    #[test]
+    fn bank_lengths() {
+        let input = "12345\n\n1234\n123456\n";
+        assert!(get_banks(input, BankLengths::Uniform).is_err());
+        let banks = get_banks(input, BankLengths::Any).unwrap();
+        let lengths: Vec<usize> = banks.iter().map(|bank| bank.batteries().len()).collect();
+        assert_eq!(lengths, [5, 4, 6]);
+        assert_eq!(total_joltage(&banks, 2).unwrap(), 45 + 34 + 56);
+
+        let banks = get_banks("1234\n5678\n", BankLengths::Exactly(4)).unwrap();
+        assert_eq!(banks.len(), 2);
+
+        // The registered solution accepts any uniform length, not just the 100 of the real input
+        let long = "9".repeat(101);
+        assert_eq!(Problem::parse(&long).unwrap()[0].batteries().len(), 101);
+        let short = "9".repeat(99);
+        assert_eq!(
+            Problem::part_1(&Problem::parse(&short).unwrap()).unwrap(),
+            99
+        );
+    }
+
+    #[test]
+    fn part_1() {
+        let banks = get_banks(EXAMPLE, BankLengths::Uniform).unwrap();
+        assert_eq!(banks.len(), 4);
+        assert_eq!(banks[0].joltage(2).unwrap(), 98);
+        assert_eq!(banks[1].joltage(2).unwrap(), 89);
+        assert_eq!(banks[2].joltage(2).unwrap(), 78);
+        assert_eq!(banks[3].joltage(2).unwrap(), 92);
+    }
+
+    #[test]
+    fn part_2() {
+        let banks = get_banks(EXAMPLE, BankLengths::Uniform).unwrap();
+        assert_eq!(banks.len(), 4);
+        assert_eq!(banks[0].joltage(12).unwrap(), 987654321111);
+        assert_eq!(banks[1].joltage(12).unwrap(), 811111111119);
+        assert_eq!(banks[2].joltage(12).unwrap(), 434234234278);
+        assert_eq!(banks[3].joltage(12).unwrap(), 888911112111);
+    }
+
+    #[test]
+    fn selections() {
+        let banks = get_banks(EXAMPLE, BankLengths::Uniform).unwrap();
+        assert_eq!(banks[0].select(2).unwrap().indices, [0, 1]);
+        assert_eq!(banks[1].select(2).unwrap().indices, [0, 14]);
+        assert_eq!(banks[3].select(2).unwrap().indices, [6, 11]);
+
+        let selection = banks[2].select(12).unwrap();
+        assert_eq!(
+            banks[2].highlight(&selection.indices),
+            "23[4]2[3][4][2][3][4][2][3][4][2][7][8]"
+        );
+        assert_eq!(banks[0].highlight(&[]), "987654321111111");
+        assert!(banks[0].select(16).is_err());
+        assert!(banks[0].select(0).unwrap().indices.is_empty());
+    }
+
+    /// Every way of choosing `k` of the first `n` indices, in ascending order
+    fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+        if k == 0 {
+            return vec![Vec::new()];
+        }
+        (k - 1..n)
+            .flat_map(|last| {
+                combinations(last, k - 1).into_iter().map(move |mut c| {
+                    c.push(last);
+                    c
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Lcg::new(3);
+        for _ in 0..200 {
+            let mut digits = String::new();
+            let len = 1 + rng.below(12) as usize;
+            for _ in 0..len {
+                // A small range of digits makes ties between selections common
+                digits.push(char::from(b'0' + rng.below(4) as u8 * 3));
+            }
+            let bank = Bank::from_string(&digits).unwrap();
+
+            for num_batteries in 0..=len {
+                let value = |indices: &[usize]| {
+                    indices
+                        .iter()
+                        .fold(0u64, |acc, &idx| acc * 10 + bank.batteries[idx] as u64)
+                };
+                let best = combinations(len, num_batteries)
+                    .into_iter()
+                    .max_by_key(|c| (value(c), std::cmp::Reverse(c.clone())))
+                    .unwrap();
+
+                let selection = bank.select(num_batteries).unwrap();
+                assert_eq!(selection.indices, best, "{digits} choosing {num_batteries}");
+                assert_eq!(bank.joltage(num_batteries).unwrap(), value(&best));
+
+                let smallest = combinations(len, num_batteries)
+                    .into_iter()
+                    .filter(|c| c.len() < 2 || bank.batteries[c[0]] != 0)
+                    .min_by_key(|c| (value(c), c.clone()));
+                match smallest {
+                    Some(smallest) => {
+                        let selection = bank.select_smallest(num_batteries).unwrap();
+                        assert_eq!(
+                            selection.indices, smallest,
+                            "{digits} choosing {num_batteries}"
+                        );
+                        assert_eq!(bank.min_joltage(num_batteries).unwrap(), value(&smallest));
+                    }
+                    None => assert!(bank.select_smallest(num_batteries).is_err()),
+                }
+
+                // The earliest selection of each joltage, largest joltage first
+                let mut ranked = combinations(len, num_batteries);
+                ranked.sort_by_key(|c| (std::cmp::Reverse(value(c)), c.clone()));
+                ranked.dedup_by_key(|c| value(c));
+                for count in [0, 1, 3, ranked.len() + 1] {
+                    let top: Vec<Vec<usize>> = bank
+                        .top_selections(num_batteries, count)
+                        .unwrap()
+                        .into_iter()
+                        .map(|selection| selection.indices)
+                        .collect();
+                    assert_eq!(
+                        top,
+                        &ranked[..count.min(ranked.len())],
+                        "{digits} top {count}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn long_selections() {
+        let digits: String = (0..100)
+            .map(|i| char::from(b'1' + (i * 7 % 9) as u8))
+            .collect();
+        let bank = Bank::from_string(&digits).unwrap();
+
+        // Every battery turned on gives back the whole bank
+        assert_eq!(bank.joltage(100).unwrap().to_string(), digits);
+
+        let joltage = bank.joltage(40).unwrap();
+        assert_eq!(joltage.digit_count(), 40);
+        let selection = bank.select(40).unwrap();
+        assert_eq!(
+            joltage.to_string(),
+            selection
+                .indices
+                .iter()
+                .map(|&idx| char::from(b'0' + bank.batteries[idx]))
+                .collect::<String>()
+        );
+
+        // Two 20 digit joltages add up to more than u64::MAX
+        let nines = "9".repeat(20);
+        let banks = get_banks(&format!("{nines}\n{nines}\n"), BankLengths::Uniform).unwrap();
+        let total = total_joltage(&banks, 20).unwrap();
+        assert_eq!(total.to_string(), "199999999999999999998");
+        assert!(u64::try_from(&total).is_err());
+    }
+
+    #[test]
+    fn smallest() {
+        let banks = get_banks(EXAMPLE, BankLengths::Uniform).unwrap();
+        assert_eq!(banks[0].min_joltage(2).unwrap(), 11);
+        assert_eq!(banks[2].min_joltage(3).unwrap(), 222);
+        assert_eq!(banks[3].min_joltage(12).unwrap(), 111911112111);
+
+        // A leading 0 would make the joltage shorter than the number of batteries
+        let bank = Bank::from_string("0504030").unwrap();
+        assert_eq!(bank.select_smallest(3).unwrap().indices, [3, 4, 6]);
+        assert_eq!(bank.min_joltage(3).unwrap(), 400);
+        assert_eq!(bank.min_joltage(1).unwrap(), 0);
+        assert!(bank.select_smallest(7).is_err());
+        assert!(
+            Bank::from_string("009")
+                .unwrap()
+                .select_smallest(3)
+                .is_err()
+        );
+        assert!(bank.select_smallest(8).is_err());
+    }
+
+    #[test]
+    fn top_selections() {
+        let bank = Bank::from_string("818181911112111").unwrap();
+        let top: Vec<String> = bank
+            .top_selections(2, 4)
+            .unwrap()
+            .iter()
+            .map(|selection| bank.joltage_of(selection).to_string())
+            .collect();
+        assert_eq!(top, ["92", "91", "89", "88"]);
+
+        let best = bank.top_selections(12, 1).unwrap();
+        assert_eq!(best, [bank.select(12).unwrap()]);
+
+        // Only one distinct joltage however many are asked for
+        let bank = Bank::from_string("7777").unwrap();
+        assert_eq!(bank.top_selections(2, 10).unwrap().len(), 1);
+        assert!(bank.top_selections(5, 1).is_err());
+    }
+}
@@ -0,0 +1,495 @@
+//! Problem 4: https://adventofcode.com/2025/day/4
+
+use crate::grid::{ALL_AROUND, Grid, ORTHOGONAL};
+use crate::{AocError, Solution};
+use std::fmt;
+
+/// Parse a rectangular grid with one row per line
+pub fn parse_grid(s: &str) -> Result<Grid<char>, AocError> {
+    s.parse()
+}
+
+/// Cells counted as neighbours of a roll
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// The four orthogonally adjacent cells
+    VonNeumann,
+    /// All eight surrounding cells, including diagonals
+    Moore,
+    /// Cells at these (`dx`,`dy`) offsets. Repeated offsets are counted once per repeat.
+    Custom(Vec<(isize, isize)>),
+}
+
+impl Neighbourhood {
+    pub fn offsets(&self) -> &[(isize, isize)] {
+        match self {
+            Neighbourhood::VonNeumann => &ORTHOGONAL,
+            Neighbourhood::Moore => &ALL_AROUND,
+            Neighbourhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// Which rolls of paper a forklift can reach. The puzzle uses [`Rules::default`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    pub neighbourhood: Neighbourhood,
+    /// A roll is accessible if fewer than this many of its neighbours are rolls
+    pub threshold: usize,
+    /// Whether neighbours past one edge of the grid wrap around to the opposite edge
+    pub wrap: bool,
+    /// Character marking a roll of paper. Every other character is empty floor.
+    pub paper: char,
+    /// Character used for empty floor when showing a grid
+    pub empty: char,
+}
+
+impl Default for Rules {
+    fn default() -> Rules {
+        Rules {
+            neighbourhood: Neighbourhood::Moore,
+            threshold: 4,
+            wrap: false,
+            paper: '@',
+            empty: '.',
+        }
+    }
+}
+
+impl Rules {
+    /// Positions whose contents count towards the neighbours of (`x`,`y`). Out of bounds
+    /// neighbours are skipped unless the grid wraps - assume no object exists there.
+    fn neighbours<'a, T>(
+        &'a self,
+        grid: &'a Grid<T>,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.neighbourhood
+            .offsets()
+            .iter()
+            .filter_map(move |&offset| self.step(grid, x, y, offset))
+    }
+
+    /// Positions that count (`x`,`y`) among their neighbours. The same as [`Rules::neighbours`]
+    /// unless a custom neighbourhood isn't symmetric.
+    fn neighbours_of<'a, T>(
+        &'a self,
+        grid: &'a Grid<T>,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.neighbourhood
+            .offsets()
+            .iter()
+            .filter_map(move |&(dx, dy)| self.step(grid, x, y, (-dx, -dy)))
+    }
+
+    fn step<T>(
+        &self,
+        grid: &Grid<T>,
+        x: usize,
+        y: usize,
+        offset: (isize, isize),
+    ) -> Option<(usize, usize)> {
+        if self.wrap {
+            return grid.wrapping_offset(x, y, offset);
+        }
+        grid.offset(x, y, offset)
+    }
+
+    fn is_paper(&self, grid: &Grid<char>, x: usize, y: usize) -> bool {
+        grid.get(x, y) == Some(&self.paper)
+    }
+
+    fn neighboring_rolls(&self, grid: &Grid<char>, x: usize, y: usize) -> usize {
+        self.neighbours(grid, x, y)
+            .filter(|&(nx, ny)| self.is_paper(grid, nx, ny))
+            .count()
+    }
+
+    /// Whether (`x`,`y`) holds a roll of paper with fewer than `threshold` rolls around it
+    pub fn is_accessible(&self, grid: &Grid<char>, x: usize, y: usize) -> bool {
+        self.is_paper(grid, x, y) && self.neighboring_rolls(grid, x, y) < self.threshold
+    }
+}
+
+/// Whether (`x`,`y`) holds a roll of paper with fewer than four rolls around it
+pub fn is_forklift_accessible(grid: &Grid<char>, x: usize, y: usize) -> bool {
+    Rules::default().is_accessible(grid, x, y)
+}
+
+/// Number of rolls that are accessible before any are taken
+pub fn accessible_rolls(grid: &Grid<char>, rules: &Rules) -> usize {
+    grid.positions()
+        .filter(|&(x, y)| rules.is_accessible(grid, x, y))
+        .count()
+}
+
+/// What happened to the contents of a single cell while the pile was eroded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Removal {
+    /// The cell never held a roll
+    NoRoll,
+    /// The roll was taken in this wave, counting from 1
+    Wave(usize),
+    /// The roll was never accessible
+    Never,
+}
+
+/// The order in which rolls are taken when every accessible roll is removed at once, wave after
+/// wave, until none are left
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Waves {
+    /// What happened to each cell of the grid
+    pub removals: Grid<Removal>,
+    /// Number of rolls taken in each wave. Wave `n` is at index `n - 1`.
+    pub removed: Vec<usize>,
+}
+
+impl Waves {
+    /// Total number of rolls taken over all waves
+    pub fn total(&self) -> usize {
+        self.removed.iter().sum()
+    }
+
+    /// Show the wave in which each roll was taken in base 36, so waves past 9 use letters. Rolls
+    /// taken after wave 35 are shown as `+`, and other cells use the characters from `rules`.
+    pub fn render(&self, rules: &Rules) -> String {
+        let mut s = String::with_capacity((self.removals.width() + 1) * self.removals.height());
+        for row in self.removals.rows() {
+            for removal in row {
+                s.push(match *removal {
+                    Removal::NoRoll => rules.empty,
+                    Removal::Never => rules.paper,
+                    Removal::Wave(wave) => char::from_digit(wave as u32, 36).unwrap_or('+'),
+                });
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+/// Shows the waves with the default characters. See [`Waves::render`].
+impl fmt::Display for Waves {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(&Rules::default()))
+    }
+}
+
+/// Remove every accessible roll in waves until none are left, recording when each was taken.
+///
+/// Each roll keeps a count of the rolls around it. Removing a roll only lowers the counts of the
+/// rolls that have it as a neighbour, so those are the only rolls that can become accessible in
+/// the next wave.
+pub fn removal_waves(grid: &Grid<char>, rules: &Rules) -> Waves {
+    // `None` for cells without a roll, either from the start or because it has been removed
+    let mut counts = grid.map(|_| None);
+    let mut removals = grid.map(|&c| {
+        if c == rules.paper {
+            Removal::Never
+        } else {
+            Removal::NoRoll
+        }
+    });
+    let mut wave = Vec::new();
+    for (x, y) in grid.positions() {
+        if rules.is_paper(grid, x, y) {
+            let count = rules.neighboring_rolls(grid, x, y);
+            if count < rules.threshold {
+                wave.push((x, y));
+            }
+            counts
+                .set(x, y, Some(count))
+                .expect("position comes from the grid");
+        }
+    }
+
+    let mut removed = Vec::new();
+    while !wave.is_empty() {
+        removed.push(wave.len());
+        let mut next = Vec::new();
+        for &(x, y) in &wave {
+            counts
+                .set(x, y, None)
+                .expect("position comes from the grid");
+            removals
+                .set(x, y, Removal::Wave(removed.len()))
+                .expect("position comes from the grid");
+
+            for (nx, ny) in rules.neighbours_of(grid, x, y) {
+                if let Some(Some(count)) = counts.get_mut(nx, ny) {
+                    *count -= 1;
+                    // Counts only go down by one at a time, so each roll is queued the one time it
+                    // drops below the threshold. Rolls in the current wave were already below it
+                    // and are never queued again.
+                    if *count + 1 == rules.threshold {
+                        next.push((nx, ny));
+                    }
+                }
+            }
+        }
+        wave = next;
+    }
+
+    Waves { removals, removed }
+}
+
+fn part_1(grid: &Grid<char>) -> usize {
+    accessible_rolls(grid, &Rules::default())
+}
+
+fn part_2(grid: &Grid<char>) -> usize {
+    removal_waves(grid, &Rules::default()).total()
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Parsed = Grid<char>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Grid<char>, AocError> {
+        parse_grid(input)
+    }
+
+    fn part_1(grid: &Grid<char>) -> Result<usize, AocError> {
+        Ok(part_1(grid))
+    }
+
+    fn part_2(grid: &Grid<char>) -> Result<usize, AocError> {
+        Ok(part_2(grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Lcg;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        ..@@.@@@@.
+        @@@.@.@.@@
+        @@@@@.@.@@
+        @.@@@@..@.
+        @@.@@@@.@@
+        .@@@@@@@.@
+        .@.@.@.@@@
+        @.@@@.@@@@
+        .@@@@@@@@.
+        @.@.@@@.@.
+    "};
+
+    #[test]
+    fn part_1() {
+        let grid = parse_grid(EXAMPLE).unwrap();
+        assert_eq!(super::part_1(&grid), 13);
+    }
+
+    #[test]
+    fn part_2() {
+        let grid = parse_grid(EXAMPLE).unwrap();
+        assert_eq!(super::part_2(&grid), 43);
+    }
+
+    #[test]
+    fn non_square() {
+        let wide = parse_grid("@@@@@\n@@@@@\n").unwrap();
+        assert_eq!((wide.width(), wide.height()), (5, 2));
+        // Corners have three neighbours and the middle of each long edge has five
+        assert_eq!(super::part_1(&wide), 4);
+        assert_eq!(super::part_2(&wide), 10);
+
+        let tall = parse_grid("@.\n@@\n@@\n.@\n@@\n").unwrap();
+        assert_eq!((tall.width(), tall.height()), (2, 5));
+        assert_eq!(super::part_1(&tall), 3);
+        assert_eq!(super::part_2(&tall), 8);
+
+        let column = parse_grid("@\n@\n@\n").unwrap();
+        assert_eq!(super::part_1(&column), 3);
+        assert!(super::is_forklift_accessible(&column, 0, 2));
+        assert!(!super::is_forklift_accessible(&column, 1, 0));
+    }
+
+    #[test]
+    fn ragged_rows() {
+        assert!(parse_grid("@@@\n@@\n").is_err());
+    }
+
+    fn try_taking(grid: &mut Grid<char>, rules: &Rules, x: usize, y: usize) -> bool {
+        if !rules.is_accessible(grid, x, y) || grid.set(x, y, rules.empty).is_err() {
+            return false;
+        }
+        true
+    }
+
+    /// The original part 2, rescanning the whole grid until a pass removes nothing
+    fn rescan_part_2(grid: &Grid<char>, rules: &Rules) -> usize {
+        let mut grid = grid.clone();
+
+        let mut curr: usize = 0;
+        let mut prev: usize = curr;
+        loop {
+            for (x, y) in grid.positions() {
+                if try_taking(&mut grid, rules, x, y) {
+                    curr += 1;
+                }
+            }
+
+            if curr == prev {
+                break;
+            }
+            prev = curr;
+        }
+
+        curr
+    }
+
+    /// A `width` x `height` map where roughly `density` percent of cells hold a roll
+    fn generated_grid(width: usize, height: usize, density: u64, seed: u64) -> Grid<char> {
+        let mut rng = Lcg::new(seed);
+        let mut text = String::new();
+        for _ in 0..height {
+            for _ in 0..width {
+                text.push(if rng.below(100) < density { '@' } else { '.' });
+            }
+            text.push('\n');
+        }
+        parse_grid(&text).unwrap()
+    }
+
+    #[test]
+    fn matches_rescan() {
+        let example = parse_grid(EXAMPLE).unwrap();
+        assert_eq!(
+            super::part_2(&example),
+            rescan_part_2(&example, &Rules::default())
+        );
+
+        let rules = [
+            Rules::default(),
+            Rules {
+                neighbourhood: Neighbourhood::VonNeumann,
+                threshold: 2,
+                ..Rules::default()
+            },
+            Rules {
+                wrap: true,
+                ..Rules::default()
+            },
+            Rules {
+                neighbourhood: Neighbourhood::Custom(vec![(1, 0), (1, 1), (0, 2), (-2, -1)]),
+                threshold: 3,
+                ..Rules::default()
+            },
+            Rules {
+                neighbourhood: Neighbourhood::Custom(vec![(1, 0), (1, 0), (0, -1)]),
+                threshold: 2,
+                wrap: true,
+                ..Rules::default()
+            },
+        ];
+
+        for seed in 0..20 {
+            let width = 1 + seed as usize % 7 * 5;
+            let height = 1 + seed as usize % 5 * 9;
+            for density in [30, 60, 75, 90] {
+                let grid = generated_grid(width, height, density, seed);
+                for rules in &rules {
+                    assert_eq!(
+                        removal_waves(&grid, rules).total(),
+                        rescan_part_2(&grid, rules),
+                        "{width}x{height} grid with density {density} and seed {seed} under {rules:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn waves() {
+        let grid = parse_grid(EXAMPLE).unwrap();
+        let waves = removal_waves(&grid, &Rules::default());
+        assert_eq!(waves.removed, [13, 12, 7, 5, 2, 1, 1, 1, 1]);
+        assert_eq!(waves.total(), 43);
+        // The first wave is everything accessible before anything is taken
+        assert_eq!(waves.removed[0], super::part_1(&grid));
+
+        assert_eq!(waves.removals.get(0, 0), Some(&Removal::NoRoll));
+        assert_eq!(waves.removals.get(2, 0), Some(&Removal::Wave(1)));
+        assert_eq!(waves.removals.get(4, 4), Some(&Removal::Never));
+
+        assert_eq!(
+            waves.to_string(),
+            indoc! {"
+                ..11.1121.
+                134.2.2.32
+                24578.1.33
+                2.69@@..2.
+                13.@@@@.21
+                .24@@@@@.2
+                .2.@.@.@@3
+                1.4@@.@@@4
+                .23@@@@@5.
+                1.1.@@@.1.
+            "}
+        );
+    }
+
+    #[test]
+    fn rules() {
+        let grid = parse_grid(EXAMPLE).unwrap();
+        let von_neumann = Rules {
+            neighbourhood: Neighbourhood::VonNeumann,
+            threshold: 2,
+            ..Rules::default()
+        };
+        assert!(von_neumann.is_accessible(&grid, 5, 0));
+        assert!(!von_neumann.is_accessible(&grid, 1, 1));
+        assert!(!von_neumann.is_accessible(&grid, 0, 0));
+
+        // Every roll in a full grid has a full neighbourhood once the edges wrap around
+        let full = parse_grid("@@@\n@@@\n@@@\n").unwrap();
+        let wrapping = Rules {
+            wrap: true,
+            ..Rules::default()
+        };
+        assert_eq!(accessible_rolls(&full, &Rules::default()), 4);
+        assert_eq!(accessible_rolls(&full, &wrapping), 0);
+        let lenient = Rules {
+            threshold: 9,
+            ..wrapping
+        };
+        assert_eq!(removal_waves(&full, &lenient).removed, [9]);
+
+        let never = Rules {
+            threshold: 0,
+            ..Rules::default()
+        };
+        assert_eq!(removal_waves(&grid, &never).total(), 0);
+    }
+
+    #[test]
+    fn custom_characters() {
+        let rules = Rules {
+            paper: '#',
+            empty: ' ',
+            ..Rules::default()
+        };
+        let text = EXAMPLE.replace('@', "#").replace('.', " ");
+        let grid = parse_grid(&text).unwrap();
+        assert_eq!(accessible_rolls(&grid, &rules), 13);
+
+        let waves = removal_waves(&grid, &rules);
+        assert_eq!(waves.total(), 43);
+        assert_eq!(
+            waves.render(&rules),
+            removal_waves(&parse_grid(EXAMPLE).unwrap(), &Rules::default())
+                .to_string()
+                .replace('@', "#")
+                .replace('.', " ")
+        );
+    }
+}
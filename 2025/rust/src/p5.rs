@@ -0,0 +1,324 @@
+//! Problem 5: https://adventofcode.com/2025/day/5
+
+use crate::interval::{IntervalSet, parse_range};
+use crate::{AocError, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::ops::RangeInclusive;
+
+/// Fresh ingredient ID ranges followed by the IDs of the available ingredients. The ranges are
+/// indexed when the inventory is made, so it can't be changed afterwards.
+pub struct Inventory {
+    fresh: Vec<RangeInclusive<u64>>,
+    available: Vec<u64>,
+    /// Union of the `fresh` ranges, for checking freshness with a binary search
+    fresh_ids: IntervalSet,
+    /// Positions of the non-empty `fresh` ranges, ordered by their lower bound
+    by_start: Vec<usize>,
+}
+
+/// Parse a single `lower-upper` range. Ranges follow the same rules as in problem 2 - see
+/// [`parse_range`].
+pub fn range_from_str(s: &str) -> Result<RangeInclusive<u64>, AocError> {
+    let line = s.trim();
+    parse_range(line, |bound| bound.parse::<u64>().ok())
+        .map_err(|message| AocError::parse(message, line))
+}
+
+/// Parse one `lower-upper` range per line
+pub fn parse_ranges(s: &str) -> Result<Vec<RangeInclusive<u64>>, AocError> {
+    let mut ranges = Vec::<RangeInclusive<u64>>::new();
+    for (idx, line) in s.lines().enumerate() {
+        let range = range_from_str(line).map_err(|e| e.at_line(idx + 1))?;
+        ranges.push(range);
+    }
+    Ok(ranges)
+}
+
+/// Parse one ID per line. `first_line` is the line number of the start of `s` in the input.
+fn parse_list(s: &str, first_line: usize) -> Result<Vec<u64>, AocError> {
+    let mut ranges = Vec::<u64>::new();
+    for (idx, line) in s.lines().enumerate() {
+        let line = line.trim();
+        ranges.push(line.parse::<u64>().map_err(|e| {
+            AocError::parse(format!("Error while parsing number: {e}"), line)
+                .at_line(first_line + idx)
+        })?);
+    }
+    Ok(ranges)
+}
+
+/// Parse the ranges and IDs sections of the puzzle input
+pub fn parse_inventory(input: &str) -> Result<Inventory, AocError> {
+    let parts: Vec<&str> = input.split("\n\n").collect();
+    if parts.len() != 2 {
+        return Err(AocError::parse(
+            format!(
+                "Expected 2 parts separated by an empty line but got {}",
+                parts.len()
+            ),
+            "",
+        ));
+    }
+
+    // The list starts after the ranges and the empty line separating them
+    let list_start = parts[0].lines().count() + 2;
+
+    Ok(Inventory::new(
+        parse_ranges(parts[0])?,
+        parse_list(parts[1], list_start)?,
+    ))
+}
+
+/// Which of the fresh ranges contain an ingredient ID
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Freshness {
+    pub id: u64,
+    /// Positions in the input of the ranges containing `id`, in ascending order
+    pub ranges: Vec<usize>,
+}
+
+impl Freshness {
+    pub fn is_fresh(&self) -> bool {
+        !self.ranges.is_empty()
+    }
+}
+
+impl Inventory {
+    /// Indexes the `fresh` ranges once, so every later lookup is a binary search
+    pub fn new(fresh: Vec<RangeInclusive<u64>>, available: Vec<u64>) -> Inventory {
+        let fresh_ids = IntervalSet::merge(fresh.iter().cloned());
+        let mut by_start: Vec<usize> = (0..fresh.len())
+            .filter(|&idx| !fresh[idx].is_empty())
+            .collect();
+        by_start.sort_by_key(|&idx| *fresh[idx].start());
+        Inventory {
+            fresh,
+            available,
+            fresh_ids,
+            by_start,
+        }
+    }
+
+    /// The fresh ID ranges, in input order
+    pub fn fresh(&self) -> &[RangeInclusive<u64>] {
+        &self.fresh
+    }
+
+    /// IDs of the available ingredients, in input order
+    pub fn available(&self) -> &[u64] {
+        &self.available
+    }
+
+    pub fn fresh_ids(&self) -> &IntervalSet {
+        &self.fresh_ids
+    }
+
+    /// Whether `id` is in any of the fresh ranges, in O(log n) for n ranges
+    pub fn is_fresh(&self, id: u64) -> bool {
+        self.fresh_ids.contains(id)
+    }
+
+    /// The ranges containing each of `ids`, in the same order as `ids`.
+    ///
+    /// The IDs are visited in ascending order while sweeping over the ranges, keeping the ranges
+    /// that have started in a heap ordered by upper bound so those that have ended can be dropped.
+    pub fn query(&self, ids: &[u64]) -> Vec<Freshness> {
+        let mut order: Vec<usize> = (0..ids.len()).collect();
+        order.sort_by_key(|&idx| ids[idx]);
+
+        let mut results: Vec<Freshness> = ids
+            .iter()
+            .map(|&id| Freshness {
+                id,
+                ranges: Vec::new(),
+            })
+            .collect();
+        let mut started = self.by_start.iter().copied().peekable();
+        let mut active = BinaryHeap::<Reverse<(u64, usize)>>::new();
+
+        for idx in order {
+            let id = ids[idx];
+            while let Some(range_idx) = started.next_if(|&r| *self.fresh[r].start() <= id) {
+                active.push(Reverse((*self.fresh[range_idx].end(), range_idx)));
+            }
+            while active.peek().is_some_and(|Reverse((end, _))| *end < id) {
+                active.pop();
+            }
+
+            let mut ranges: Vec<usize> = active.iter().map(|Reverse((_, idx))| *idx).collect();
+            ranges.sort_unstable();
+            results[idx].ranges = ranges;
+        }
+        results
+    }
+}
+
+fn part_1(inventory: &Inventory) -> u64 {
+    inventory
+        .available
+        .iter()
+        .filter(|&&id| inventory.is_fresh(id))
+        .count() as u64
+}
+
+fn part_2(inventory: &Inventory) -> Result<u64, AocError> {
+    u64::try_from(inventory.fresh_ids.coverage())
+        .map_err(|_| AocError::invalid_state("Number of fresh IDs doesn't fit in a u64"))
+}
+
+pub struct Problem;
+
+impl Solution for Problem {
+    type Parsed = Inventory;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Inventory, AocError> {
+        parse_inventory(input)
+    }
+
+    fn part_1(inventory: &Inventory) -> Result<u64, AocError> {
+        Ok(part_1(inventory))
+    }
+
+    fn part_2(inventory: &Inventory) -> Result<u64, AocError> {
+        part_2(inventory)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AocError, Inventory};
+    use crate::test_support::Lcg;
+    use indoc::{formatdoc, indoc};
+
+    const EXAMPLE: &str = indoc! {"
+        3-5
+        10-14
+        16-20
+        12-18
+
+        1
+        5
+        8
+        11
+        17
+        32
+    "};
+
+    #[test]
+    fn parse_ranges() {
+        let input = formatdoc! {"
+            0-0
+            1-3
+            47389-473214324
+            {}-{}
+            ",
+            u64::MIN, u64::MAX,
+        };
+        let expected = vec![0u64..=0, 1u64..=3, 47389..=473214324, u64::MIN..=u64::MAX];
+        assert_eq!(super::parse_ranges(input.as_str()).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = super::parse_inventory("3-5\n10-x\n\n1\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Line 2: upper bound is not a non-negative integer: \"10-x\""
+        );
+
+        let error = super::parse_inventory("3-5\n10-14\n\n1\n5\neight\n")
+            .err()
+            .unwrap();
+        assert!(matches!(error, AocError::Parse { line: Some(6), .. }));
+
+        // Ranges follow the same rules as in problem 2
+        for range in ["1-2-3", "+5-7", "9-4"] {
+            let error = super::parse_inventory(&format!("3-5\n{range}\n\n1\n")).err();
+            assert!(
+                matches!(error, Some(AocError::Parse { line: Some(2), .. })),
+                "{range}"
+            );
+        }
+    }
+
+    #[test]
+    fn part_1() {
+        let inventory = super::parse_inventory(EXAMPLE).unwrap();
+        assert_eq!(super::part_1(&inventory), 3);
+    }
+
+    #[test]
+    fn query() {
+        let inventory = super::parse_inventory(EXAMPLE).unwrap();
+        let found: Vec<(u64, Vec<usize>)> = inventory
+            .query(&inventory.available)
+            .into_iter()
+            .map(|freshness| (freshness.id, freshness.ranges))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (1, vec![]),
+                (5, vec![0]),
+                (8, vec![]),
+                (11, vec![1]),
+                (17, vec![2, 3]),
+                (32, vec![]),
+            ]
+        );
+        assert!(inventory.query(&[17])[0].is_fresh());
+        assert!(!inventory.query(&[32])[0].is_fresh());
+        assert!(inventory.query(&[]).is_empty());
+    }
+
+    #[test]
+    fn matches_linear_scan() {
+        let mut rng = Lcg::new(11);
+        let mut next = |limit: u64| rng.below(limit);
+
+        for _ in 0..300 {
+            let fresh: Vec<_> = (0..next(8))
+                .map(|_| {
+                    let lower = next(50);
+                    // Some ranges are empty, which the parser rejects but an inventory accepts
+                    lower..=(lower + next(20)).saturating_sub(5)
+                })
+                .collect();
+            let ids: Vec<u64> = (0..next(12)).map(|_| next(80)).collect();
+
+            let inventory = Inventory::new(fresh.clone(), ids.clone());
+            for freshness in inventory.query(&ids) {
+                let expected: Vec<usize> = (0..fresh.len())
+                    .filter(|&idx| fresh[idx].contains(&freshness.id))
+                    .collect();
+                assert_eq!(freshness.ranges, expected, "{fresh:?} {}", freshness.id);
+                assert_eq!(inventory.is_fresh(freshness.id), freshness.is_fresh());
+            }
+            assert_eq!(
+                inventory
+                    .query(&ids)
+                    .iter()
+                    .map(|f| f.id)
+                    .collect::<Vec<_>>(),
+                ids
+            );
+            assert_eq!(
+                super::part_1(&inventory),
+                ids.iter()
+                    .filter(|id| fresh.iter().any(|r| r.contains(id)))
+                    .count() as u64
+            );
+        }
+    }
+
+    #[test]
+    fn part_2() {
+        let inventory = super::parse_inventory(EXAMPLE).unwrap();
+        assert_eq!(super::part_2(&inventory).unwrap(), 14);
+
+        let everything = super::parse_inventory("0-18446744073709551615\n\n1\n").unwrap();
+        assert!(super::part_2(&everything).is_err());
+    }
+}
@@ -1,65 +1,24 @@
 //! Problem 6: https://adventofcode.com/2025/day/6
 
-use std::str::FromStr;
+use crate::grid::Grid;
+use crate::{AocError, Solution};
 
-#[derive(Debug)]
-struct Grid {
-    rows: usize,
-    cols: usize,
-    data: Vec<char>,
+/// Iterator over the columns of the grid from left to right, each collected top to bottom
+fn iter_columns(grid: &Grid<char>) -> impl Iterator<Item = Vec<char>> + '_ {
+    grid.columns().map(|col| col.copied().collect())
 }
 
-impl FromStr for Grid {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Grid, Self::Err> {
-        let lines: Vec<&str> = s.lines().filter(|x| !x.is_empty()).collect();
-
-        if lines.is_empty() {
-            return Err(String::from("Empty file"));
-        }
-
-        let expected_cols = lines[0].len();
-        let mut data = Vec::with_capacity(expected_cols * lines.len());
-        for (idx, line) in lines.iter().enumerate() {
-            if line.len() != expected_cols {
-                return Err(format!(
-                    "Non-uniform row length at line {idx}. Expected {expected_cols}, got {}",
-                    line.len()
-                ));
-            }
-            data.extend(line.chars());
-        }
-
-        Ok(Self {
-            rows: lines.len(),
-            cols: expected_cols,
-            data,
-        })
-    }
-}
-
-impl Grid {
-    /// A vector of characters representing a column at the specified index
-    fn get_column(&self, col: usize) -> Vec<char> {
-        let mut result = Vec::with_capacity(self.rows);
-        for row in 0..self.rows {
-            result.push(self.data[row * self.cols + col]);
-        }
-        result
-    }
-
-    /// Iterator over the columns of the grid from left to right
-    fn iter_columns(&self) -> impl Iterator<Item = Vec<char>> + '_ {
-        (0..self.cols).map(|col| self.get_column(col))
-    }
-}
-
-fn calculate(op: char, args: &[String]) -> Result<u64, String> {
+fn calculate(op: char, args: &[String]) -> Result<u64, AocError> {
     let mut nums = Vec::<u64>::new();
     for arg in args {
         match arg.parse::<u64>() {
             Ok(num) => nums.push(num),
-            Err(e) => return Err(format!("Unable to parse \"{arg}\" as a number: {e}.")),
+            Err(e) => {
+                return Err(AocError::parse(
+                    format!("Unable to parse as a number: {e}"),
+                    arg,
+                ));
+            }
         }
     }
 
@@ -67,22 +26,21 @@ fn calculate(op: char, args: &[String]) -> Result<u64, String> {
         '*' => nums.iter().fold(1u64, |acc, &x| acc.saturating_mul(x)),
         '+' => nums.iter().sum(),
         ' ' => 0,
-        unexpected => return Err(format!("Unexpected operation: {unexpected}.")),
+        unexpected => return Err(AocError::parse("Unexpected operation", unexpected)),
     };
     Ok(val)
 }
 
-fn part_1(input: &str) -> Result<u64, String> {
-    let grid = Grid::from_str(input)?;
-    if grid.rows < 2 {
-        return Err(String::from("Grid must have at least 2 rows."));
+fn part_1(grid: &Grid<char>) -> Result<u64, AocError> {
+    if grid.height() < 2 {
+        return Err(AocError::invalid_state("Grid must have at least 2 rows."));
     }
 
     let mut operation = ' ';
     let mut result = 0u64;
-    let mut args: Vec<String> = vec![String::new(); grid.rows - 1];
+    let mut args: Vec<String> = vec![String::new(); grid.height() - 1];
 
-    for col in grid.iter_columns() {
+    for col in iter_columns(grid) {
         if col == vec![' '; col.len()] {
             // All numbers considered finished on a blank column - reset parsing state
             result += calculate(operation, &args)?;
@@ -100,7 +58,7 @@ fn part_1(input: &str) -> Result<u64, String> {
 
         operation = match col.last() {
             None => {
-                return Err(String::from(
+                return Err(AocError::invalid_state(
                     "Empty column being processed, even though it should have been handled already.",
                 ));
             }
@@ -108,9 +66,10 @@ fn part_1(input: &str) -> Result<u64, String> {
             Some('+') => '+',
             Some(' ') => operation,
             Some(unexpected) => {
-                return Err(format!(
-                    "Unexpected operation character \"{unexpected}\".\""
-                ));
+                return Err(
+                    AocError::parse("Unexpected operation character", *unexpected)
+                        .at_line(grid.height()),
+                );
             }
         }
     }
@@ -121,17 +80,16 @@ fn part_1(input: &str) -> Result<u64, String> {
     Ok(result)
 }
 
-fn part_2(input: &str) -> Result<u64, String> {
-    let grid = Grid::from_str(input)?;
-    if grid.rows < 2 {
-        return Err(String::from("Grid must have at least 2 rows."));
+fn part_2(grid: &Grid<char>) -> Result<u64, AocError> {
+    if grid.height() < 2 {
+        return Err(AocError::invalid_state("Grid must have at least 2 rows."));
     }
 
     let mut operation = ' ';
     let mut result = 0u64;
     let mut args = Vec::<String>::new();
 
-    for col in grid.iter_columns() {
+    for col in iter_columns(grid) {
         if col == vec![' '; col.len()] {
             // All numbers considered finished on a blank column - reset parsing state
             result += calculate(operation, &args)?;
@@ -144,7 +102,7 @@ fn part_2(input: &str) -> Result<u64, String> {
         let chars_string = String::from_iter(col[..(col.len() - 1)].iter());
         let num = chars_string.trim();
         if num.is_empty() {
-            return Err(String::from(
+            return Err(AocError::invalid_state(
                 "Empty column being processed, even though it should have been handled already.",
             ));
         } else {
@@ -153,7 +111,7 @@ fn part_2(input: &str) -> Result<u64, String> {
 
         operation = match col.last() {
             None => {
-                return Err(String::from(
+                return Err(AocError::invalid_state(
                     "Empty column being processed, even though it should have been handled already.",
                 ));
             }
@@ -161,9 +119,10 @@ fn part_2(input: &str) -> Result<u64, String> {
             Some('+') => '+',
             Some(' ') => operation,
             Some(unexpected) => {
-                return Err(format!(
-                    "Unexpected operation character \"{unexpected}\".\""
-                ));
+                return Err(
+                    AocError::parse("Unexpected operation character", *unexpected)
+                        .at_line(grid.height()),
+                );
             }
         }
     }
@@ -174,31 +133,28 @@ fn part_2(input: &str) -> Result<u64, String> {
     Ok(result)
 }
 
-pub fn p6_1() {
-    match part_1(super::input_to_string(6, 1).as_str()) {
-        Ok(answer) => {
-            println!("Problem 06 Part 1: {}", answer);
-        }
-        Err(e) => {
-            eprintln!("Problem 06 Part 1: ERROR: {}", e);
-        }
+pub struct Problem;
+
+impl Solution for Problem {
+    type Parsed = Grid<char>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Grid<char>, AocError> {
+        input.parse()
     }
-}
 
-pub fn p6_2() {
-    match part_2(super::input_to_string(6, 1).as_str()) {
-        Ok(answer) => {
-            println!("Problem 06 Part 2: {}", answer);
-        }
-        Err(e) => {
-            eprintln!("Problem 06 Part 2: ERROR: {}", e);
-        }
+    fn part_1(grid: &Grid<char>) -> Result<u64, AocError> {
+        part_1(grid)
+    }
+
+    fn part_2(grid: &Grid<char>) -> Result<u64, AocError> {
+        part_2(grid)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Grid;
+    use super::{AocError, Grid, iter_columns};
     use indoc::concatdoc;
 
     const EXAMPLE: &str = concatdoc!(
@@ -211,14 +167,14 @@ mod tests {
     #[test]
     fn grid_from_string() {
         let grid = EXAMPLE
-            .parse::<Grid>()
+            .parse::<Grid<char>>()
             .map_err(|e| format!("Couldn't parse example: {e}"));
         let expected: Vec<char> = EXAMPLE.chars().filter(|c| *c != '\n').collect();
         match grid {
             Ok(grid) => {
-                assert_eq!(grid.data, expected);
-                assert_eq!(grid.rows, 4);
-                assert_eq!(grid.cols, 15);
+                assert_eq!(grid.rows().flatten().copied().collect::<Vec<_>>(), expected);
+                assert_eq!(grid.height(), 4);
+                assert_eq!(grid.width(), 15);
             }
             Err(e) => panic!("Failed to parse example: {e}"),
         }
@@ -227,11 +183,8 @@ mod tests {
     #[test]
     fn iterate_columns() {
         // Handled in `grid_from_string`
-        let columns = EXAMPLE
-            .parse::<Grid>()
-            .unwrap()
-            .iter_columns()
-            .collect::<Vec<_>>();
+        let grid = EXAMPLE.parse::<Grid<char>>().unwrap();
+        let columns = iter_columns(&grid).collect::<Vec<_>>();
         let expected = [
             ['1', ' ', ' ', '*'],
             ['2', '4', ' ', ' '],
@@ -255,12 +208,27 @@ mod tests {
     #[test]
     fn part_1() {
         // Handled in `grid_from_string`
-        assert_eq!(super::part_1(EXAMPLE), Ok(4277556));
+        let grid = EXAMPLE.parse::<Grid<char>>().unwrap();
+        assert_eq!(super::part_1(&grid).unwrap(), 4277556);
     }
 
     #[test]
     fn part_2() {
         // Handled in `grid_from_string`
-        assert_eq!(super::part_2(EXAMPLE), Ok(3263827));
+        let grid = EXAMPLE.parse::<Grid<char>>().unwrap();
+        assert_eq!(super::part_2(&grid).unwrap(), 3263827);
+    }
+
+    #[test]
+    fn too_few_rows() {
+        let grid = "123\n".parse::<Grid<char>>().unwrap();
+        assert!(matches!(
+            super::part_1(&grid),
+            Err(AocError::InvalidState(_))
+        ));
+        assert!(matches!(
+            super::part_2(&grid),
+            Err(AocError::InvalidState(_))
+        ));
     }
 }
@@ -0,0 +1,355 @@
+//! Output of runner results
+
+use crate::runner::{DayReport, Failure, Samples};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How results are written to stdout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable answers, or a table of durations when benchmarking
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Format, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "Unknown format \"{s}\". Expected text, json or csv."
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Ok,
+    Error,
+    MissingInput,
+}
+
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Error => "error",
+            Status::MissingInput => "missing_input",
+        }
+    }
+}
+
+/// Outcome of a single (day, part), flattened for machine-readable output. Durations are medians
+/// when benchmarking.
+#[derive(Debug, PartialEq, Eq)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    status: Status,
+    answer: Option<&'a str>,
+    error: Option<&'a str>,
+    read: Duration,
+    parse: Duration,
+    solve: Duration,
+}
+
+fn records(reports: &[DayReport]) -> Vec<Record<'_>> {
+    let mut records = Vec::new();
+    for report in reports {
+        let record = |part: u8, status: Status| Record {
+            year: report.year,
+            day: report.day,
+            part,
+            status,
+            answer: None,
+            error: None,
+            read: report.read.median(),
+            parse: report.parse.median(),
+            solve: Duration::ZERO,
+        };
+
+        match &report.parts {
+            Ok(parts) => {
+                for part in parts {
+                    let (status, answer, error) = match &part.answer {
+                        Ok(answer) => (Status::Ok, Some(answer.as_str()), None),
+                        Err(e) => (Status::Error, None, Some(e.as_str())),
+                    };
+                    records.push(Record {
+                        answer,
+                        error,
+                        solve: part.time.median(),
+                        ..record(part.part, status)
+                    });
+                }
+            }
+            Err(failure) => {
+                let (status, message) = match failure {
+                    Failure::MissingInput(message) => (Status::MissingInput, message),
+                    Failure::Error(message) => (Status::Error, message),
+                };
+                for &part in &report.selected {
+                    records.push(Record {
+                        error: Some(message.as_str()),
+                        ..record(part, status)
+                    });
+                }
+            }
+        }
+    }
+    records
+}
+
+/// Print the answers for one day, followed by how long each phase took if `time` is set
+pub fn print_answers(report: &DayReport, time: bool) {
+    let mut timings = vec![
+        format!("read {:.2?}", report.read.min()),
+        format!("parse {:.2?}", report.parse.min()),
+    ];
+
+    match &report.parts {
+        Ok(parts) => {
+            for part in parts {
+                match &part.answer {
+                    Ok(answer) => {
+                        println!("Problem {:02} Part {}: {answer}", report.day, part.part)
+                    }
+                    Err(e) => eprintln!("Problem {:02} Part {}: ERROR: {e}", report.day, part.part),
+                }
+                match &part.explanation {
+                    Some(Ok(explanation)) => print!("{explanation}"),
+                    Some(Err(e)) => eprintln!(
+                        "Problem {:02} Part {}: ERROR explaining answer: {e}",
+                        report.day, part.part
+                    ),
+                    None => {}
+                }
+                timings.push(format!("part {} {:.2?}", part.part, part.time.min()));
+            }
+        }
+        Err(Failure::MissingInput(e)) => eprintln!("Problem {:02}: INPUT MISSING: {e}", report.day),
+        Err(Failure::Error(e)) => eprintln!("Problem {:02}: ERROR: {e}", report.day),
+    }
+
+    if time {
+        println!("Problem {:02} Time: {}", report.day, timings.join(", "));
+    }
+}
+
+/// Print min/median/mean durations of every phase, with a total across all days
+pub fn print_bench(reports: &[DayReport]) {
+    let mut rows: Vec<(String, &Samples)> = Vec::new();
+    for report in reports {
+        rows.push((format!("{:02} read", report.day), &report.read));
+        rows.push((format!("{:02} parse", report.day), &report.parse));
+        match &report.parts {
+            Ok(parts) => {
+                for part in parts {
+                    if let Err(e) = &part.answer {
+                        eprintln!("Problem {:02} Part {}: ERROR: {e}", report.day, part.part);
+                    }
+                    rows.push((format!("{:02} part {}", report.day, part.part), &part.time));
+                }
+            }
+            Err(e) => eprintln!("Problem {:02}: ERROR: {e}", report.day),
+        }
+    }
+
+    let row = |name: &str, min: Duration, median: Duration, mean: Duration| {
+        println!("{name:<12} {min:>12.2?} {median:>12.2?} {mean:>12.2?}");
+    };
+
+    println!(
+        "{:<12} {:>12} {:>12} {:>12}",
+        "Phase", "Min", "Median", "Mean"
+    );
+    let (mut min, mut median, mut mean) = (Duration::ZERO, Duration::ZERO, Duration::ZERO);
+    for (name, samples) in rows {
+        row(&name, samples.min(), samples.median(), samples.mean());
+        min += samples.min();
+        median += samples.median();
+        mean += samples.mean();
+    }
+    row("Total", min, median, mean);
+}
+
+/// Print one JSON object per (day, part) as an array
+pub fn print_json(reports: &[DayReport]) {
+    println!("{}", to_json(&records(reports)));
+}
+
+/// Print one CSV row per (day, part), preceded by a header
+pub fn print_csv(reports: &[DayReport]) {
+    print!("{}", to_csv(&records(reports)));
+}
+
+fn to_json(records: &[Record]) -> String {
+    let string = |value: Option<&str>| match value {
+        Some(value) => json_string(value),
+        None => String::from("null"),
+    };
+
+    let objects: Vec<String> = records
+        .iter()
+        .map(|r| {
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"status\": \"{}\", \"answer\": {}, \
+                 \"error\": {}, \"read_ns\": {}, \"parse_ns\": {}, \"solve_ns\": {}}}",
+                r.year,
+                r.day,
+                r.part,
+                r.status.as_str(),
+                string(r.answer),
+                string(r.error),
+                r.read.as_nanos(),
+                r.parse.as_nanos(),
+                r.solve.as_nanos(),
+            )
+        })
+        .collect();
+
+    if objects.is_empty() {
+        return String::from("[]");
+    }
+    format!("[\n{}\n]", objects.join(",\n"))
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn to_csv(records: &[Record]) -> String {
+    let mut csv = String::from("year,day,part,status,answer,error,read_ns,parse_ns,solve_ns\n");
+    for r in records {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{}\n",
+            r.year,
+            r.day,
+            r.part,
+            r.status.as_str(),
+            csv_field(r.answer.unwrap_or_default()),
+            csv_field(r.error.unwrap_or_default()),
+            r.read.as_nanos(),
+            r.parse.as_nanos(),
+            r.solve.as_nanos(),
+        ));
+    }
+    csv
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(
+        status: Status,
+        answer: Option<&'static str>,
+        error: Option<&'static str>,
+    ) -> Record<'static> {
+        Record {
+            year: 2025,
+            day: 4,
+            part: 2,
+            status,
+            answer,
+            error,
+            read: Duration::from_nanos(10),
+            parse: Duration::from_nanos(20),
+            solve: Duration::from_nanos(30),
+        }
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn missing_input_records_every_selected_part() {
+        let report = DayReport {
+            year: 2025,
+            day: 4,
+            selected: vec![1, 2],
+            read: Samples::default(),
+            parse: Samples::default(),
+            parts: Err(Failure::MissingInput(String::from("No input"))),
+        };
+        let records = records(std::slice::from_ref(&report));
+        assert_eq!(records.len(), 2);
+        for (record, part) in records.iter().zip([1, 2]) {
+            assert_eq!(record.part, part);
+            assert_eq!(record.status, Status::MissingInput);
+            assert_eq!(record.error, Some("No input"));
+            assert_eq!(record.answer, None);
+        }
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(to_json(&[]), "[]");
+        let records = [
+            record(Status::Ok, Some("43"), None),
+            record(Status::Error, None, Some("Bad \"grid\"\nat line 2")),
+        ];
+        assert_eq!(
+            to_json(&records),
+            concat!(
+                "[\n",
+                "  {\"year\": 2025, \"day\": 4, \"part\": 2, \"status\": \"ok\", \"answer\": \"43\", ",
+                "\"error\": null, \"read_ns\": 10, \"parse_ns\": 20, \"solve_ns\": 30},\n",
+                "  {\"year\": 2025, \"day\": 4, \"part\": 2, \"status\": \"error\", \"answer\": null, ",
+                "\"error\": \"Bad \\\"grid\\\"\\nat line 2\", \"read_ns\": 10, \"parse_ns\": 20, ",
+                "\"solve_ns\": 30}\n",
+                "]",
+            )
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let records = [
+            record(Status::Ok, Some("43"), None),
+            record(Status::MissingInput, None, Some("Not found, \"4.1.txt\"")),
+        ];
+        assert_eq!(
+            to_csv(&records),
+            concat!(
+                "year,day,part,status,answer,error,read_ns,parse_ns,solve_ns\n",
+                "2025,4,2,ok,43,,10,20,30\n",
+                "2025,4,2,missing_input,,\"Not found, \"\"4.1.txt\"\"\",10,20,30\n",
+            )
+        );
+    }
+}
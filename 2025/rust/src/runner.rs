@@ -0,0 +1,221 @@
+//! Runs registered days one phase at a time, measuring how long each phase takes
+
+use crate::{Day, InputError, InputSource};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Durations recorded for repeated runs of a single phase
+#[derive(Debug, Clone, Default)]
+pub struct Samples(Vec<Duration>);
+
+impl Samples {
+    /// Run `f` `repeats` times, keeping the result of the last run
+    fn measure<T>(repeats: usize, mut f: impl FnMut() -> T) -> (T, Samples) {
+        let mut durations = Vec::with_capacity(repeats);
+        let mut result = None;
+        for _ in 0..repeats.max(1) {
+            let start = Instant::now();
+            result = Some(f());
+            durations.push(start.elapsed());
+        }
+        (
+            result.expect("Phases run at least once"),
+            Samples(durations),
+        )
+    }
+
+    pub fn min(&self) -> Duration {
+        self.0.iter().copied().min().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.0.clone();
+        sorted.sort();
+        match sorted.len() {
+            0 => Duration::ZERO,
+            n if n % 2 == 1 => sorted[n / 2],
+            n => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        }
+    }
+
+    pub fn mean(&self) -> Duration {
+        if self.0.is_empty() {
+            return Duration::ZERO;
+        }
+        self.0.iter().sum::<Duration>() / self.0.len() as u32
+    }
+}
+
+/// Answer and timings for a single part
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<String, String>,
+    pub time: Samples,
+    /// How the answer is made up, if one was requested and the day can give it
+    pub explanation: Option<Result<String, String>>,
+}
+
+/// Why a day produced no answers at all
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// There is no puzzle input for the day
+    MissingInput(String),
+    /// The input couldn't be read or parsed
+    Error(String),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::MissingInput(message) | Failure::Error(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/// Everything measured while running the selected parts of one day
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    /// Parts that were requested, whether or not they could be run
+    pub selected: Vec<u8>,
+    pub read: Samples,
+    pub parse: Samples,
+    /// `Err` if the input couldn't be read or parsed, in which case no parts were run
+    pub parts: Result<Vec<PartReport>, Failure>,
+}
+
+/// Merge selected `(problem, part)` pairs into the parts to run for each problem, so that every
+/// problem's input is read and parsed only once. A part of `None` selects both parts. Problems
+/// keep the order they were first selected in, and each problem's parts are in ascending order.
+pub fn parts_by_day<I>(selections: I) -> Vec<(u8, Vec<u8>)>
+where
+    I: IntoIterator<Item = (u8, Option<u8>)>,
+{
+    let mut days: Vec<(u8, Vec<u8>)> = Vec::new();
+    for (problem, part) in selections {
+        let idx = match days.iter().position(|(day, _)| *day == problem) {
+            Some(idx) => idx,
+            None => {
+                days.push((problem, Vec::new()));
+                days.len() - 1
+            }
+        };
+        let parts = &mut days[idx].1;
+        parts.extend(part.map_or(vec![1, 2], |part| vec![part]));
+        parts.sort_unstable();
+        parts.dedup();
+    }
+    days
+}
+
+/// Read, parse and solve `parts` of `day`, repeating every phase `repeats` times. Stdin can only be
+/// read once, so reading from it is never repeated. If `explain` is set, each part is also
+/// explained, outside of the timed phases.
+pub fn run_day(
+    day: &Day,
+    parts: &[u8],
+    repeats: usize,
+    source: &InputSource,
+    explain: bool,
+) -> DayReport {
+    let mut report = DayReport {
+        year: day.year,
+        day: day.day,
+        selected: parts.to_vec(),
+        read: Samples::default(),
+        parse: Samples::default(),
+        parts: Ok(Vec::new()),
+    };
+
+    let read_repeats = match source {
+        InputSource::Stdin => 1,
+        _ => repeats,
+    };
+    let (input, read) = Samples::measure(read_repeats, || source.read(day.day, 1));
+    report.read = read;
+    let input = match input {
+        Ok(input) => input,
+        Err(e @ InputError::NotFound { .. }) => {
+            report.parts = Err(Failure::MissingInput(e.to_string()));
+            return report;
+        }
+        Err(e) => {
+            report.parts = Err(Failure::Error(e.to_string()));
+            return report;
+        }
+    };
+
+    let (parsed, parse) = Samples::measure(repeats, || day.parse(&input));
+    report.parse = parse;
+
+    report.parts = parsed
+        .map_err(|e| Failure::Error(e.to_string()))
+        .map(|parsed| {
+            parts
+                .iter()
+                .map(|&part| {
+                    let (answer, time) = Samples::measure(repeats, || day.solve(&parsed, part));
+                    let answer = answer.map_err(|e| e.to_string());
+                    let explanation = explain
+                        .then(|| day.explain(&parsed, part))
+                        .flatten()
+                        .map(|explanation| explanation.map_err(|e| e.to_string()));
+                    PartReport {
+                        part,
+                        answer,
+                        time,
+                        explanation,
+                    }
+                })
+                .collect()
+        });
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(micros: &[u64]) -> Samples {
+        Samples(micros.iter().map(|&us| Duration::from_micros(us)).collect())
+    }
+
+    #[test]
+    fn statistics() {
+        let odd = samples(&[30, 10, 20, 100, 40]);
+        assert_eq!(odd.min(), Duration::from_micros(10));
+        assert_eq!(odd.median(), Duration::from_micros(30));
+        assert_eq!(odd.mean(), Duration::from_micros(40));
+
+        let even = samples(&[40, 10, 20, 30]);
+        assert_eq!(even.median(), Duration::from_micros(25));
+
+        let empty = Samples::default();
+        assert_eq!(empty.min(), Duration::ZERO);
+        assert_eq!(empty.median(), Duration::ZERO);
+        assert_eq!(empty.mean(), Duration::ZERO);
+    }
+
+    #[test]
+    fn merged_selections() {
+        assert_eq!(
+            parts_by_day([(4, Some(2)), (1, None), (4, Some(1))]),
+            [(4, vec![1, 2]), (1, vec![1, 2])]
+        );
+        assert_eq!(parts_by_day([(4, Some(1)), (4, None)]), [(4, vec![1, 2])]);
+        assert_eq!(parts_by_day([(3, Some(2)), (3, Some(2))]), [(3, vec![2])]);
+        assert!(parts_by_day([]).is_empty());
+    }
+
+    #[test]
+    fn measure_repeats() {
+        let mut calls = 0;
+        let (result, samples) = Samples::measure(3, || {
+            calls += 1;
+            calls
+        });
+        assert_eq!(result, 3);
+        assert_eq!(samples.0.len(), 3);
+    }
+}
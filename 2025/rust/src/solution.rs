@@ -0,0 +1,95 @@
+//! Common interface implemented by every problem, and the registry used to look them up
+
+use crate::AocError;
+use std::any::Any;
+use std::fmt::Display;
+
+/// A puzzle solution. Input is parsed once with [`Solution::parse`] and then handed to each part.
+pub trait Solution {
+    /// Puzzle input after parsing
+    type Parsed: 'static;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError>;
+    fn part_1(parsed: &Self::Parsed) -> Result<Self::Answer, AocError>;
+    fn part_2(parsed: &Self::Parsed) -> Result<Self::Answer, AocError>;
+
+    /// Describe how the answer to `part` is made up, for days where that helps with debugging.
+    /// `None` if the day has no explanation to give.
+    fn explain(_parsed: &Self::Parsed, _part: u8) -> Option<Result<String, AocError>> {
+        None
+    }
+}
+
+/// Parsed input for a [`Day`], shared between both parts. Only the day that produced it can solve
+/// it.
+pub struct ParsedInput(Box<dyn Any>);
+
+/// A registered solution for a single day, with its types erased so that every day can be stored
+/// in the same list
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    parse: fn(&str) -> Result<ParsedInput, AocError>,
+    solve: fn(&ParsedInput, u8) -> Result<String, AocError>,
+    explain: fn(&ParsedInput, u8) -> Option<Result<String, AocError>>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(year: u16, day: u8) -> Day {
+        Day {
+            year,
+            day,
+            parse: parse::<S>,
+            solve: solve::<S>,
+            explain: explain::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<ParsedInput, AocError> {
+        (self.parse)(input)
+    }
+
+    /// Solve `part` (1 or 2) of the puzzle from input returned by [`Day::parse`]
+    pub fn solve(&self, parsed: &ParsedInput, part: u8) -> Result<String, AocError> {
+        (self.solve)(parsed, part)
+    }
+
+    /// Explain the answer to `part` of the puzzle, if the day supports it. See
+    /// [`Solution::explain`].
+    pub fn explain(&self, parsed: &ParsedInput, part: u8) -> Option<Result<String, AocError>> {
+        (self.explain)(parsed, part)
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<ParsedInput, AocError> {
+    Ok(ParsedInput(Box::new(S::parse(input)?)))
+}
+
+fn downcast<S: Solution>(parsed: &ParsedInput, part: u8) -> Result<&S::Parsed, AocError> {
+    if !(1..=2).contains(&part) {
+        return Err(AocError::invalid_state(format!(
+            "Part {part} does not exist. Expected 1 or 2."
+        )));
+    }
+    parsed
+        .0
+        .downcast_ref::<S::Parsed>()
+        .ok_or_else(|| AocError::invalid_state("Parsed input was produced by a different day."))
+}
+
+fn solve<S: Solution>(parsed: &ParsedInput, part: u8) -> Result<String, AocError> {
+    let parsed = downcast::<S>(parsed, part)?;
+    let answer = match part {
+        1 => S::part_1(parsed)?,
+        _ => S::part_2(parsed)?,
+    };
+    Ok(answer.to_string())
+}
+
+fn explain<S: Solution>(parsed: &ParsedInput, part: u8) -> Option<Result<String, AocError>> {
+    match downcast::<S>(parsed, part) {
+        Ok(parsed) => S::explain(parsed, part),
+        Err(e) => Some(Err(e)),
+    }
+}
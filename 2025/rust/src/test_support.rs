@@ -0,0 +1,25 @@
+//! Helpers shared by the unit tests
+
+/// Linear congruential generator for reproducible random test inputs
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Lcg {
+        Lcg(seed)
+    }
+
+    /// Advance the generator and return its whole state. The low bits are not very random, so
+    /// prefer [`Lcg::below`] unless the caller picks out high bits itself.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0
+    }
+
+    /// A number in `0..limit`, taken from the high bits of the state
+    pub fn below(&mut self, limit: u64) -> u64 {
+        (self.next_u64() >> 33) % limit
+    }
+}
@@ -0,0 +1,54 @@
+//! Runs the `rust` binary end to end
+
+use indoc::indoc;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const DAY_4_EXAMPLE: &str = indoc! {"
+    ..@@.@@@@.
+    @@@.@.@.@@
+    @@@@@.@.@@
+    @.@@@@..@.
+    @@.@@@@.@@
+    .@@@@@@@.@
+    .@.@.@.@@@
+    @.@@@.@@@@
+    .@@@@@@@@.
+    @.@.@@@.@.
+"};
+
+fn run_with_stdin(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rust"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Binary runs");
+    child
+        .stdin
+        .take()
+        .expect("Stdin is piped")
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn stdin_is_read_once_per_day() {
+    let expected = "Problem 04 Part 1: 13\nProblem 04 Part 2: 43\n";
+    for args in [
+        ["--input", "-", "4.1", "4.2"],
+        ["--input", "-", "4.2", "4.1"],
+        ["--input", "-", "4.1", "4"],
+    ] {
+        let output = run_with_stdin(&args, DAY_4_EXAMPLE);
+        assert!(output.status.success(), "{args:?}");
+        assert_eq!(
+            String::from_utf8(output.stdout).unwrap(),
+            expected,
+            "{args:?}"
+        );
+        assert!(output.stderr.is_empty(), "{args:?}");
+    }
+}
@@ -0,0 +1,123 @@
+//! Runs each registered day on its example input through the public library API
+
+use aoc::{AocError, DAYS, YEAR, find_day};
+use indoc::indoc;
+
+fn solve(day: u8, input: &str) -> Result<(String, String), AocError> {
+    let day = find_day(YEAR, day).expect("Day is registered");
+    let parsed = day.parse(input)?;
+    Ok((day.solve(&parsed, 1)?, day.solve(&parsed, 2)?))
+}
+
+#[test]
+fn every_day_is_registered() {
+    let days: Vec<u8> = DAYS.iter().map(|day| day.day).collect();
+    assert_eq!(days, [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn examples() {
+    let examples = [
+        (
+            1,
+            "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n",
+            "3",
+            "6",
+        ),
+        (
+            2,
+            "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,\
+             446443-446449,38593856-38593862,565653-565659,824824821-824824827,\
+             2121212118-2121212124\n",
+            "1227775554",
+            "4174379265",
+        ),
+        (
+            3,
+            "987654321111111\n811111111111119\n234234234234278\n818181911112111\n",
+            "357",
+            "3121910778619",
+        ),
+        (
+            4,
+            indoc! {"
+                ..@@.@@@@.
+                @@@.@.@.@@
+                @@@@@.@.@@
+                @.@@@@..@.
+                @@.@@@@.@@
+                .@@@@@@@.@
+                .@.@.@.@@@
+                @.@@@.@@@@
+                .@@@@@@@@.
+                @.@.@@@.@.
+            "},
+            "13",
+            "43",
+        ),
+        (
+            5,
+            "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n",
+            "3",
+            "14",
+        ),
+        (
+            6,
+            "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n",
+            "4277556",
+            "3263827",
+        ),
+    ];
+
+    for (day, input, part_1, part_2) in examples {
+        let (answer_1, answer_2) = solve(day, input).unwrap();
+        assert_eq!(answer_1, part_1, "Day {day} part 1");
+        assert_eq!(answer_2, part_2, "Day {day} part 2");
+    }
+}
+
+#[test]
+fn public_parsers() {
+    let turn = aoc::p1::DialTurn::from_instruction("R42").unwrap();
+    assert_eq!(turn.amount, 42);
+
+    let ranges = aoc::p2::parse_ranges("11-22,95-115").unwrap();
+    assert_eq!(ranges, [11..=22, 95..=115]);
+
+    let bank = aoc::p3::Bank::from_string("987654321111111").unwrap();
+    assert_eq!(bank.joltage(2).unwrap(), 98);
+
+    // Day 3's registered solution expects every bank to be the same length
+    assert!(matches!(
+        solve(3, "987654321111111\n98765432111111\n"),
+        Err(AocError::Parse { line: Some(2), .. })
+    ));
+}
+
+#[test]
+fn explanations() {
+    let day = find_day(YEAR, 2).expect("Day is registered");
+    let parsed = day.parse("11-22,95-115\n").unwrap();
+    assert_eq!(
+        day.explain(&parsed, 1).unwrap().unwrap(),
+        indoc! {r#"
+            11-22: 2 invalid IDs, subtotal 33
+              11 = "1" × 2
+              22 = "2" × 2
+            95-115: 1 invalid ID, subtotal 99
+              99 = "9" × 2
+        "#}
+    );
+    assert!(day.explain(&parsed, 3).unwrap().is_err());
+
+    let day = find_day(YEAR, 3).expect("Day is registered");
+    let parsed = day.parse("811111111111119\n").unwrap();
+    assert_eq!(
+        day.explain(&parsed, 1).unwrap().unwrap(),
+        "[8]1111111111111[9] = 89\n"
+    );
+
+    let day = find_day(YEAR, 1).expect("Day is registered");
+    let parsed = day.parse("L68\n").unwrap();
+    assert!(day.explain(&parsed, 1).is_none());
+}